@@ -0,0 +1,68 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{Real, SimdReal};
+use core::simd::{LaneCount, SupportedLaneCount};
+
+/// Accumulator of lane-parallel partial sums reducing them horizontally only once.
+///
+/// Accumulates vertically into a vector of `N` partial sums, e.g., the dot product of two slices
+/// split into SIMD vectors, and reduces them to a single sum by [`Self::finish()`] at the end.
+#[derive(Debug, Clone, Copy)]
+pub struct Accumulator<R: Real, const N: usize>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	sum: R::Simd<N>,
+}
+
+impl<R: Real, const N: usize> Accumulator<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	/// Creates accumulator with partial sums of zero.
+	#[must_use]
+	#[inline]
+	pub fn new() -> Self {
+		Self {
+			sum: R::Simd::splat(R::ZERO),
+		}
+	}
+	/// Adds the lanes of `a` to the partial sums.
+	#[inline]
+	pub fn add(&mut self, a: R::Simd<N>) {
+		self.sum += a;
+	}
+	/// Adds the lanewise products of `a` and `b` to the partial sums.
+	///
+	/// The products are fused with the sums, see [`SimdReal::mul_add()`].
+	#[inline]
+	pub fn add_dot(&mut self, a: R::Simd<N>, b: R::Simd<N>) {
+		self.sum = a.mul_add(b, self.sum);
+	}
+	/// Returns the lane-parallel partial sums.
+	#[must_use]
+	#[inline]
+	pub const fn partial_sums(&self) -> R::Simd<N> {
+		self.sum
+	}
+	/// Reduces the partial sums horizontally to their total sum.
+	#[must_use]
+	#[inline]
+	pub fn finish(self) -> R {
+		self.sum.reduce_sum()
+	}
+}
+
+impl<R: Real, const N: usize> Default for Accumulator<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
@@ -0,0 +1,719 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{ApproxEq, Real, SimdReal};
+use core::{
+	fmt::Debug,
+	ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+	simd::{LaneCount, SupportedLaneCount},
+};
+
+/// Subset of [`Real`] implemented by [`Real`] itself and by [`Dual`] numbers over [`Real`].
+///
+/// Functions generic over `T: RealLike<R>` evaluate on plain numbers of `R` and, without any
+/// change, on [`Dual<R>`] numbers computing derivatives alongside the values.
+pub trait RealLike<R: Real>
+where
+	Self: Clone + Copy + Default + Debug + PartialEq,
+	Self: ApproxEq<R, Self> + From<R>,
+	Self: Add<Output = Self> + AddAssign,
+	Self: Sub<Output = Self> + SubAssign,
+	Self: Mul<Output = Self> + MulAssign,
+	Self: Div<Output = Self> + DivAssign,
+	Self: Mul<R, Output = Self> + MulAssign<R>,
+	Self: Div<R, Output = Self> + DivAssign<R>,
+	Self: Neg<Output = Self>,
+{
+	/// Computes the sine.
+	#[must_use]
+	fn sin(self) -> Self;
+	/// Computes the cosine.
+	#[must_use]
+	fn cos(self) -> Self;
+	/// Computes the exponential function.
+	#[must_use]
+	fn exp(self) -> Self;
+	/// Computes the natural logarithm.
+	#[must_use]
+	fn ln(self) -> Self;
+	/// Computes the square root.
+	#[must_use]
+	fn sqrt(self) -> Self;
+	/// Raises to the constant power `n`.
+	#[must_use]
+	fn powf(self, n: R) -> Self;
+	/// Takes the reciprocal (inverse).
+	#[must_use]
+	fn recip(self) -> Self;
+}
+
+impl<R: Real> RealLike<R> for R {
+	#[inline]
+	fn sin(self) -> Self {
+		Real::sin(self)
+	}
+	#[inline]
+	fn cos(self) -> Self {
+		Real::cos(self)
+	}
+	#[inline]
+	fn exp(self) -> Self {
+		Real::exp(self)
+	}
+	#[inline]
+	fn ln(self) -> Self {
+		Real::ln(self)
+	}
+	#[inline]
+	fn sqrt(self) -> Self {
+		Real::sqrt(self)
+	}
+	#[inline]
+	fn powf(self, n: R) -> Self {
+		Real::powf(self, n)
+	}
+	#[inline]
+	fn recip(self) -> Self {
+		Real::recip(self)
+	}
+}
+
+/// Dual number of a value and its derivative for forward-mode automatic differentiation.
+///
+/// Evaluating a function of dual numbers with a [`Self::variable()`] at $x$ computes $f(x)$ as
+/// [`Self::value()`] and $f'(x)$ as [`Self::derivative()`] at once by applying the chain rule to
+/// every operation. Numbers the function does not depend on are [`Self::constant()`]s. Functions
+/// generic over [`RealLike`] accept both, plain numbers and dual numbers.
+///
+/// # Examples
+///
+/// ```
+/// use lav::{ApproxEqRel, Dual, RealLike};
+///
+/// fn f<T: RealLike<f64>>(x: T) -> T {
+/// 	let two = T::from(2.0);
+/// 	(x.sin() * x.exp() - x.cos() / x.sqrt()) * x.ln()
+/// 		+ x.powf(2.5) * two / (x * x + two).recip()
+/// }
+///
+/// // Central finite difference.
+/// let h = 1e-6;
+/// for i in 1..=40 {
+/// 	let x = f64::from(i) / 10.0;
+/// 	let y = f(Dual::variable(x));
+/// 	let dy = (f(x + h) - f(x - h)) / (h + h);
+/// 	assert!(y.value().approx_eq_rel(&f(x), 1e-15));
+/// 	assert!(y.derivative().approx_eq_rel(&dy, 1e-6));
+/// }
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Dual<R: Real> {
+	value: R,
+	derivative: R,
+}
+
+impl<R: Real> Dual<R> {
+	/// Creates dual number of `value` and `derivative`.
+	#[must_use]
+	#[inline]
+	pub const fn new(value: R, derivative: R) -> Self {
+		Self { value, derivative }
+	}
+	/// Creates dual number of the independent variable `value` with a derivative of one.
+	#[must_use]
+	#[inline]
+	pub const fn variable(value: R) -> Self {
+		Self::new(value, R::ONE)
+	}
+	/// Creates dual number of the constant `value` with a derivative of zero.
+	#[must_use]
+	#[inline]
+	pub const fn constant(value: R) -> Self {
+		Self::new(value, R::ZERO)
+	}
+	/// Returns the value.
+	#[must_use]
+	#[inline]
+	pub const fn value(&self) -> R {
+		self.value
+	}
+	/// Returns the derivative.
+	#[must_use]
+	#[inline]
+	pub const fn derivative(&self) -> R {
+		self.derivative
+	}
+	/// Computes the sine.
+	#[must_use]
+	#[inline]
+	pub fn sin(self) -> Self {
+		let (sin, cos) = self.value.sin_cos();
+		Self::new(sin, cos * self.derivative)
+	}
+	/// Computes the cosine.
+	#[must_use]
+	#[inline]
+	pub fn cos(self) -> Self {
+		let (sin, cos) = self.value.sin_cos();
+		Self::new(cos, -sin * self.derivative)
+	}
+	/// Computes the exponential function.
+	#[must_use]
+	#[inline]
+	pub fn exp(self) -> Self {
+		let exp = self.value.exp();
+		Self::new(exp, exp * self.derivative)
+	}
+	/// Computes the natural logarithm.
+	#[must_use]
+	#[inline]
+	pub fn ln(self) -> Self {
+		Self::new(self.value.ln(), self.derivative / self.value)
+	}
+	/// Computes the square root.
+	///
+	/// The derivative is infinite at zero.
+	#[must_use]
+	#[inline]
+	pub fn sqrt(self) -> Self {
+		let sqrt = self.value.sqrt();
+		Self::new(sqrt, self.derivative / (sqrt + sqrt))
+	}
+	/// Raises to the constant power `n`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Dual;
+	///
+	/// let x = Dual::variable(0.0f32);
+	/// assert_eq!(x.powf(0.5).value(), 0.0);
+	/// assert_eq!(x.powf(2.0), Dual::new(0.0, 0.0));
+	/// assert_eq!(Dual::variable(-2.0f32).powf(3.0), Dual::new(-8.0, 12.0));
+	/// assert_eq!(Dual::variable(4.0f32).powf(0.5), Dual::new(2.0, 0.25));
+	/// ```
+	#[must_use]
+	#[inline]
+	pub fn powf(self, n: R) -> Self {
+		Self::new(
+			self.value.powf(n),
+			n * self.value.powf(n - R::ONE) * self.derivative,
+		)
+	}
+	/// Takes the reciprocal (inverse).
+	#[must_use]
+	#[inline]
+	pub fn recip(self) -> Self {
+		let recip = self.value.recip();
+		Self::new(recip, -recip * recip * self.derivative)
+	}
+}
+
+impl<R: Real> Default for Dual<R> {
+	#[inline]
+	fn default() -> Self {
+		Self::constant(R::ZERO)
+	}
+}
+
+impl<R: Real> From<R> for Dual<R> {
+	#[inline]
+	fn from(value: R) -> Self {
+		Self::constant(value)
+	}
+}
+
+impl<R: Real> RealLike<R> for Dual<R> {
+	#[inline]
+	fn sin(self) -> Self {
+		Self::sin(self)
+	}
+	#[inline]
+	fn cos(self) -> Self {
+		Self::cos(self)
+	}
+	#[inline]
+	fn exp(self) -> Self {
+		Self::exp(self)
+	}
+	#[inline]
+	fn ln(self) -> Self {
+		Self::ln(self)
+	}
+	#[inline]
+	fn sqrt(self) -> Self {
+		Self::sqrt(self)
+	}
+	#[inline]
+	fn powf(self, n: R) -> Self {
+		Self::powf(self, n)
+	}
+	#[inline]
+	fn recip(self) -> Self {
+		Self::recip(self)
+	}
+}
+
+impl<R: Real> ApproxEq<R> for Dual<R> {
+	#[inline]
+	fn approx_eq(&self, other: &Self, epsilon: R, ulp: R::Bits) -> bool {
+		self.value.approx_eq(&other.value, epsilon, ulp)
+			&& self.derivative.approx_eq(&other.derivative, epsilon, ulp)
+	}
+}
+
+impl<R: Real> Add for Dual<R> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, other: Self) -> Self::Output {
+		Self::new(self.value + other.value, self.derivative + other.derivative)
+	}
+}
+
+impl<R: Real> AddAssign for Dual<R> {
+	#[inline]
+	fn add_assign(&mut self, other: Self) {
+		*self = *self + other;
+	}
+}
+
+impl<R: Real> Sub for Dual<R> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, other: Self) -> Self::Output {
+		Self::new(self.value - other.value, self.derivative - other.derivative)
+	}
+}
+
+impl<R: Real> SubAssign for Dual<R> {
+	#[inline]
+	fn sub_assign(&mut self, other: Self) {
+		*self = *self - other;
+	}
+}
+
+impl<R: Real> Mul for Dual<R> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: Self) -> Self::Output {
+		Self::new(
+			self.value * other.value,
+			self.derivative
+				.mul_add(other.value, self.value * other.derivative),
+		)
+	}
+}
+
+impl<R: Real> MulAssign for Dual<R> {
+	#[inline]
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
+	}
+}
+
+impl<R: Real> Mul<R> for Dual<R> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: R) -> Self::Output {
+		Self::new(self.value * other, self.derivative * other)
+	}
+}
+
+impl<R: Real> MulAssign<R> for Dual<R> {
+	#[inline]
+	fn mul_assign(&mut self, other: R) {
+		*self = *self * other;
+	}
+}
+
+impl<R: Real> Div for Dual<R> {
+	type Output = Self;
+
+	#[inline]
+	fn div(self, other: Self) -> Self::Output {
+		let value = self.value / other.value;
+		Self::new(
+			value,
+			(-value).mul_add(other.derivative, self.derivative) / other.value,
+		)
+	}
+}
+
+impl<R: Real> DivAssign for Dual<R> {
+	#[inline]
+	fn div_assign(&mut self, other: Self) {
+		*self = *self / other;
+	}
+}
+
+impl<R: Real> Div<R> for Dual<R> {
+	type Output = Self;
+
+	#[inline]
+	fn div(self, other: R) -> Self::Output {
+		Self::new(self.value / other, self.derivative / other)
+	}
+}
+
+impl<R: Real> DivAssign<R> for Dual<R> {
+	#[inline]
+	fn div_assign(&mut self, other: R) {
+		*self = *self / other;
+	}
+}
+
+impl<R: Real> Neg for Dual<R> {
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self::new(-self.value, -self.derivative)
+	}
+}
+
+/// SIMD vector of [`Dual`] numbers for forward-mode automatic differentiation at `N` points.
+///
+/// Holds the values and the derivatives as separate vectors of `N` lanes, i.e., as structure of
+/// arrays, so evaluating a function of SIMD dual numbers with a [`Self::variable()`] computes
+/// $f(x)$ and $f'(x)$ at `N` points $x$ at once.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use core::simd::Simd;
+/// use lav::{ApproxEqRel, SimdDual};
+///
+/// fn f(x: SimdDual<f64, 4>) -> SimdDual<f64, 4> {
+/// 	let two = SimdDual::constant(Simd::splat(2.0));
+/// 	(x.sin() * x.exp() - x.cos() / x.sqrt()) * x.ln()
+/// 		+ x.powf(2.5) * two / (x * x + two).recip()
+/// }
+///
+/// // Central finite difference.
+/// let h = Simd::splat(1e-6);
+/// for i in 0..10 {
+/// 	let x = Simd::from_array([0.1, 0.2, 0.3, 0.4]) + Simd::splat(f64::from(i) * 0.4);
+/// 	let y = f(SimdDual::variable(x));
+/// 	let dy =
+/// 		(f(SimdDual::constant(x + h)).value() - f(SimdDual::constant(x - h)).value()) / (h + h);
+/// 	assert_eq!(y.value(), f(SimdDual::constant(x)).value());
+/// 	assert!(y.derivative().approx_eq_rel(&dy, 1e-6));
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SimdDual<R: Real, const N: usize>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	value: R::Simd<N>,
+	derivative: R::Simd<N>,
+}
+
+impl<R: Real, const N: usize> SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	/// Creates SIMD dual numbers of `value` and `derivative` vectors.
+	#[must_use]
+	#[inline]
+	pub const fn new(value: R::Simd<N>, derivative: R::Simd<N>) -> Self {
+		Self { value, derivative }
+	}
+	/// Creates SIMD dual numbers of the independent variable `value` with derivatives of one.
+	#[must_use]
+	#[inline]
+	pub fn variable(value: R::Simd<N>) -> Self {
+		Self::new(value, R::Simd::splat(R::ONE))
+	}
+	/// Creates SIMD dual numbers of the constant `value` with derivatives of zero.
+	#[must_use]
+	#[inline]
+	pub fn constant(value: R::Simd<N>) -> Self {
+		Self::new(value, R::Simd::splat(R::ZERO))
+	}
+	/// Creates SIMD dual numbers by setting all lanes to the given dual number.
+	#[must_use]
+	#[inline]
+	pub fn splat(dual: Dual<R>) -> Self {
+		Self::new(
+			R::Simd::splat(dual.value()),
+			R::Simd::splat(dual.derivative()),
+		)
+	}
+	/// Returns the values.
+	#[must_use]
+	#[inline]
+	pub const fn value(&self) -> R::Simd<N> {
+		self.value
+	}
+	/// Returns the derivatives.
+	#[must_use]
+	#[inline]
+	pub const fn derivative(&self) -> R::Simd<N> {
+		self.derivative
+	}
+	/// Returns the dual number of lane `index`.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds, i.e., not less than `N`.
+	#[must_use]
+	#[inline]
+	pub fn lane(&self, index: usize) -> Dual<R> {
+		Dual::new(self.value[index], self.derivative[index])
+	}
+	/// Computes the sine of each lane.
+	#[must_use]
+	#[inline]
+	pub fn sin(self) -> Self {
+		let (sin, cos) = self.value.sin_cos();
+		Self::new(sin, cos * self.derivative)
+	}
+	/// Computes the cosine of each lane.
+	#[must_use]
+	#[inline]
+	pub fn cos(self) -> Self {
+		let (sin, cos) = self.value.sin_cos();
+		Self::new(cos, -sin * self.derivative)
+	}
+	/// Computes the exponential function of each lane.
+	///
+	/// Maps each lane via [`Real::exp()`].
+	#[must_use]
+	#[inline]
+	pub fn exp(self) -> Self {
+		let exp = map::<R, N>(self.value, Real::exp);
+		Self::new(exp, exp * self.derivative)
+	}
+	/// Computes the natural logarithm of each lane.
+	///
+	/// Maps each lane via [`Real::ln()`].
+	#[must_use]
+	#[inline]
+	pub fn ln(self) -> Self {
+		Self::new(
+			map::<R, N>(self.value, Real::ln),
+			self.derivative / self.value,
+		)
+	}
+	/// Computes the square root of each lane.
+	///
+	/// The derivative is infinite at zero.
+	#[must_use]
+	#[inline]
+	pub fn sqrt(self) -> Self {
+		let sqrt = self.value.sqrt();
+		Self::new(sqrt, self.derivative / (sqrt + sqrt))
+	}
+	/// Raises each lane to the constant power `n`.
+	///
+	/// Maps each lane via [`Real::powf()`].
+	#[must_use]
+	#[inline]
+	pub fn powf(self, n: R) -> Self {
+		Self::new(
+			map::<R, N>(self.value, |x| x.powf(n)),
+			R::Simd::splat(n) * map::<R, N>(self.value, |x| x.powf(n - R::ONE)) * self.derivative,
+		)
+	}
+	/// Takes the reciprocal (inverse) of each lane.
+	#[must_use]
+	#[inline]
+	pub fn recip(self) -> Self {
+		let recip = self.value.recip();
+		Self::new(recip, -recip * recip * self.derivative)
+	}
+}
+
+impl<R: Real, const N: usize> Default for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::constant(R::Simd::splat(R::ZERO))
+	}
+}
+
+impl<R: Real, const N: usize> ApproxEq<R> for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn approx_eq(&self, other: &Self, epsilon: R, ulp: R::Bits) -> bool {
+		self.value.approx_eq(&other.value, epsilon, ulp)
+			&& self.derivative.approx_eq(&other.derivative, epsilon, ulp)
+	}
+}
+
+impl<R: Real, const N: usize> Add for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn add(self, other: Self) -> Self::Output {
+		Self::new(self.value + other.value, self.derivative + other.derivative)
+	}
+}
+
+impl<R: Real, const N: usize> AddAssign for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn add_assign(&mut self, other: Self) {
+		*self = *self + other;
+	}
+}
+
+impl<R: Real, const N: usize> Sub for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, other: Self) -> Self::Output {
+		Self::new(self.value - other.value, self.derivative - other.derivative)
+	}
+}
+
+impl<R: Real, const N: usize> SubAssign for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn sub_assign(&mut self, other: Self) {
+		*self = *self - other;
+	}
+}
+
+impl<R: Real, const N: usize> Mul for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: Self) -> Self::Output {
+		Self::new(
+			self.value * other.value,
+			self.derivative
+				.mul_add(other.value, self.value * other.derivative),
+		)
+	}
+}
+
+impl<R: Real, const N: usize> MulAssign for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
+	}
+}
+
+impl<R: Real, const N: usize> Mul<R> for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: R) -> Self::Output {
+		let other = R::Simd::splat(other);
+		Self::new(self.value * other, self.derivative * other)
+	}
+}
+
+impl<R: Real, const N: usize> MulAssign<R> for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn mul_assign(&mut self, other: R) {
+		*self = *self * other;
+	}
+}
+
+impl<R: Real, const N: usize> Div for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn div(self, other: Self) -> Self::Output {
+		let value = self.value / other.value;
+		Self::new(
+			value,
+			(-value).mul_add(other.derivative, self.derivative) / other.value,
+		)
+	}
+}
+
+impl<R: Real, const N: usize> DivAssign for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn div_assign(&mut self, other: Self) {
+		*self = *self / other;
+	}
+}
+
+impl<R: Real, const N: usize> Div<R> for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn div(self, other: R) -> Self::Output {
+		let other = R::Simd::splat(other);
+		Self::new(self.value / other, self.derivative / other)
+	}
+}
+
+impl<R: Real, const N: usize> DivAssign<R> for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn div_assign(&mut self, other: R) {
+		*self = *self / other;
+	}
+}
+
+impl<R: Real, const N: usize> Neg for SimdDual<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self::new(-self.value, -self.derivative)
+	}
+}
+
+/// Maps each lane of `x` via `f`.
+fn map<R: Real, const N: usize>(x: R::Simd<N>, f: impl FnMut(R) -> R) -> R::Simd<N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	R::Simd::from_array(x.to_array().map(f))
+}
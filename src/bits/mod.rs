@@ -15,6 +15,8 @@ use core::{
 	simd::{LaneCount, SimdElement, SupportedLaneCount},
 };
 
+mod i32;
+mod i64;
 mod u32;
 mod u64;
 
@@ -74,13 +76,103 @@ where
 
 	/// Absolute subtract.
 	///
-	/// Equals `self.saturating_sub(other) | other.saturating_sub(self)`.
+	/// Equals `self.max(other).saturating_sub(self.min(other))`. Saturates at [`Self::MAX`] for
+	/// signed integers if the difference overflows, e.g., if either is [`Self::MIN`] and the other
+	/// is not negative.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Bits;
+	///
+	/// assert_eq!(i32::MIN.abs_sub(i32::MAX), i32::MAX);
+	/// assert_eq!(i32::MAX.abs_sub(i32::MIN), i32::MAX);
+	/// assert_eq!((-1i32).abs_sub(1), 2);
+	/// assert_eq!(i32::MIN.abs_sub(i32::MIN), 0);
+	/// assert_eq!(i64::MIN.abs_sub(0), i64::MAX);
+	/// assert_eq!(i64::MAX.abs_sub(-1), i64::MAX);
+	/// assert_eq!(i64::MIN.abs_sub(-1), i64::MAX);
+	/// assert_eq!(3u32.abs_sub(5), 2);
+	/// ```
 	#[must_use]
 	#[inline]
 	fn abs_sub(self, other: Self) -> Self {
-		self.saturating_sub(other) | other.saturating_sub(self)
+		self.max(other).saturating_sub(self.min(other))
 	}
 
+	/// Encodes the 2D coordinates `x` and `y` as Morton code (Z-order).
+	///
+	/// Interleaves the bits of `x` and `y` with the bits of `x` at even positions and the bits of
+	/// `y` at odd positions. Only the lower half of the bits of [`Self`] are encoded per
+	/// coordinate, i.e., 16 bits for [`prim@u32`] and 32 bits for [`prim@u64`], higher bits are
+	/// discarded. Signed integers reinterpret the code of their unsigned counterparts.
+	#[must_use]
+	fn morton_encode_2d(x: u32, y: u32) -> Self;
+	/// Decodes Morton code (Z-order) into the 2D coordinates `(x, y)`.
+	///
+	/// Inverse of [`Self::morton_encode_2d()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Bits;
+	///
+	/// assert_eq!(u64::morton_encode_2d(0b11, 0b01), 0b0111);
+	/// for (x, y) in [(0, 0), (7, 3), (u32::MAX, 0), (0x1234_5678, 0x9abc_def0)] {
+	/// 	assert_eq!(u64::morton_encode_2d(x, y).morton_decode_2d(), (x, y));
+	/// 	assert_eq!(
+	/// 		u32::morton_encode_2d(x, y).morton_decode_2d(),
+	/// 		(x & 0xffff, y & 0xffff)
+	/// 	);
+	/// 	assert_eq!(i64::morton_encode_2d(x, y).morton_decode_2d(), (x, y));
+	/// }
+	/// ```
+	#[must_use]
+	fn morton_decode_2d(self) -> (u32, u32);
+	/// Encodes the 3D coordinates `x`, `y`, and `z` as Morton code (Z-order).
+	///
+	/// Interleaves the bits of `x`, `y`, and `z` with the bits of `x` at positions divisible by
+	/// three followed by the bits of `y` and `z`. Only the lower third of the bits of [`Self`] are
+	/// encoded per coordinate, i.e., 10 bits for [`prim@u32`] and 21 bits for [`prim@u64`], higher
+	/// bits are discarded. Signed integers reinterpret the code of their unsigned counterparts.
+	#[must_use]
+	fn morton_encode_3d(x: u32, y: u32, z: u32) -> Self;
+	/// Decodes Morton code (Z-order) into the 3D coordinates `(x, y, z)`.
+	///
+	/// Inverse of [`Self::morton_encode_3d()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Bits;
+	///
+	/// assert_eq!(u32::morton_encode_3d(1, 1, 1), 0b111);
+	/// assert_eq!(u64::morton_encode_3d(0, 0, 1 << 20), 1 << 62);
+	/// for (x, y, z) in [
+	/// 	(0, 0, 0),
+	/// 	(7, 3, 5),
+	/// 	(u32::MAX, 0, 1),
+	/// 	(0x1234_5678, 0x9abc_def0, 42),
+	/// ] {
+	/// 	let (x21, y21, z21) = (x & 0x1f_ffff, y & 0x1f_ffff, z & 0x1f_ffff);
+	/// 	assert_eq!(
+	/// 		u64::morton_encode_3d(x, y, z).morton_decode_3d(),
+	/// 		(x21, y21, z21)
+	/// 	);
+	/// 	let (x10, y10, z10) = (x & 0x3ff, y & 0x3ff, z & 0x3ff);
+	/// 	assert_eq!(
+	/// 		u32::morton_encode_3d(x, y, z).morton_decode_3d(),
+	/// 		(x10, y10, z10)
+	/// 	);
+	/// 	assert_eq!(
+	/// 		i32::morton_encode_3d(x, y, z).morton_decode_3d(),
+	/// 		(x10, y10, z10)
+	/// 	);
+	/// }
+	/// ```
+	#[must_use]
+	fn morton_decode_3d(self) -> (u32, u32, u32);
+
 	/// Constructs a SIMD vector by setting all lanes to the given value.
 	#[must_use]
 	#[inline]
@@ -26,4 +26,65 @@ impl Bits for u32 {
 	fn saturating_sub(self, other: Self) -> Self {
 		self.saturating_sub(other)
 	}
+
+	#[inline]
+	fn morton_encode_2d(x: u32, y: u32) -> Self {
+		spread_2d(x) | spread_2d(y) << 1
+	}
+	#[inline]
+	fn morton_decode_2d(self) -> (u32, u32) {
+		(compact_2d(self), compact_2d(self >> 1))
+	}
+	#[inline]
+	fn morton_encode_3d(x: u32, y: u32, z: u32) -> Self {
+		spread_3d(x) | spread_3d(y) << 1 | spread_3d(z) << 2
+	}
+	#[inline]
+	fn morton_decode_3d(self) -> (u32, u32, u32) {
+		(
+			compact_3d(self),
+			compact_3d(self >> 1),
+			compact_3d(self >> 2),
+		)
+	}
+}
+
+/// Spreads the lower 16 bits to the even bit positions.
+#[inline]
+const fn spread_2d(mut x: u32) -> u32 {
+	x &= 0x0000_ffff;
+	x = (x | x << 8) & 0x00ff_00ff;
+	x = (x | x << 4) & 0x0f0f_0f0f;
+	x = (x | x << 2) & 0x3333_3333;
+	(x | x << 1) & 0x5555_5555
+}
+
+/// Compacts the even bit positions to the lower 16 bits.
+#[inline]
+const fn compact_2d(mut x: u32) -> u32 {
+	x &= 0x5555_5555;
+	x = (x | x >> 1) & 0x3333_3333;
+	x = (x | x >> 2) & 0x0f0f_0f0f;
+	x = (x | x >> 4) & 0x00ff_00ff;
+	(x | x >> 8) & 0x0000_ffff
+}
+
+/// Spreads the lower 10 bits to the bit positions divisible by three.
+#[inline]
+const fn spread_3d(mut x: u32) -> u32 {
+	x &= 0x0000_03ff;
+	x = (x | x << 16) & 0x0300_00ff;
+	x = (x | x << 8) & 0x0300_f00f;
+	x = (x | x << 4) & 0x030c_30c3;
+	(x | x << 2) & 0x0924_9249
+}
+
+/// Compacts the bit positions divisible by three to the lower 10 bits.
+#[inline]
+const fn compact_3d(mut x: u32) -> u32 {
+	x &= 0x0924_9249;
+	x = (x | x >> 2) & 0x030c_30c3;
+	x = (x | x >> 4) & 0x0300_f00f;
+	x = (x | x >> 8) & 0x0300_00ff;
+	(x | x >> 16) & 0x0000_03ff
 }
@@ -26,4 +26,71 @@ impl Bits for u64 {
 	fn saturating_sub(self, other: Self) -> Self {
 		self.saturating_sub(other)
 	}
+
+	#[inline]
+	fn morton_encode_2d(x: u32, y: u32) -> Self {
+		spread_2d(x.into()) | spread_2d(y.into()) << 1
+	}
+	#[allow(clippy::cast_possible_truncation)]
+	#[inline]
+	fn morton_decode_2d(self) -> (u32, u32) {
+		(compact_2d(self) as u32, compact_2d(self >> 1) as u32)
+	}
+	#[inline]
+	fn morton_encode_3d(x: u32, y: u32, z: u32) -> Self {
+		spread_3d(x.into()) | spread_3d(y.into()) << 1 | spread_3d(z.into()) << 2
+	}
+	#[allow(clippy::cast_possible_truncation)]
+	#[inline]
+	fn morton_decode_3d(self) -> (u32, u32, u32) {
+		(
+			compact_3d(self) as u32,
+			compact_3d(self >> 1) as u32,
+			compact_3d(self >> 2) as u32,
+		)
+	}
+}
+
+/// Spreads the lower 32 bits to the even bit positions.
+#[inline]
+const fn spread_2d(mut x: u64) -> u64 {
+	x &= 0x0000_0000_ffff_ffff;
+	x = (x | x << 16) & 0x0000_ffff_0000_ffff;
+	x = (x | x << 8) & 0x00ff_00ff_00ff_00ff;
+	x = (x | x << 4) & 0x0f0f_0f0f_0f0f_0f0f;
+	x = (x | x << 2) & 0x3333_3333_3333_3333;
+	(x | x << 1) & 0x5555_5555_5555_5555
+}
+
+/// Compacts the even bit positions to the lower 32 bits.
+#[inline]
+const fn compact_2d(mut x: u64) -> u64 {
+	x &= 0x5555_5555_5555_5555;
+	x = (x | x >> 1) & 0x3333_3333_3333_3333;
+	x = (x | x >> 2) & 0x0f0f_0f0f_0f0f_0f0f;
+	x = (x | x >> 4) & 0x00ff_00ff_00ff_00ff;
+	x = (x | x >> 8) & 0x0000_ffff_0000_ffff;
+	(x | x >> 16) & 0x0000_0000_ffff_ffff
+}
+
+/// Spreads the lower 21 bits to the bit positions divisible by three.
+#[inline]
+const fn spread_3d(mut x: u64) -> u64 {
+	x &= 0x0000_0000_001f_ffff;
+	x = (x | x << 32) & 0x001f_0000_0000_ffff;
+	x = (x | x << 16) & 0x001f_0000_ff00_00ff;
+	x = (x | x << 8) & 0x100f_00f0_0f00_f00f;
+	x = (x | x << 4) & 0x10c3_0c30_c30c_30c3;
+	(x | x << 2) & 0x1249_2492_4924_9249
+}
+
+/// Compacts the bit positions divisible by three to the lower 21 bits.
+#[inline]
+const fn compact_3d(mut x: u64) -> u64 {
+	x &= 0x1249_2492_4924_9249;
+	x = (x | x >> 2) & 0x10c3_0c30_c30c_30c3;
+	x = (x | x >> 4) & 0x100f_00f0_0f00_f00f;
+	x = (x | x >> 8) & 0x001f_0000_ff00_00ff;
+	x = (x | x >> 16) & 0x001f_0000_0000_ffff;
+	(x | x >> 32) & 0x0000_0000_001f_ffff
 }
@@ -0,0 +1,111 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::Real;
+use bytemuck::Pod;
+use core::simd::{LaneCount, SupportedLaneCount};
+
+/// Casts a slice of lanes into a slice of vectors without copying.
+///
+/// Unlike [`Real::as_simd()`], there is neither a prefix nor a suffix of lanes. Hence, the
+/// slice must start at an address aligned to the vector, which is usually `N` times the
+/// alignment of a lane, and its length must be a multiple of `N`. Allocate the lanes as vectors
+/// and cast them back via [`cast_lanes()`] to satisfy the alignment requirement.
+///
+/// # Panics
+///
+/// Panics if the length of `slice` is not a multiple of `N` or if `slice` is misaligned.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use core::simd::Simd;
+/// use lav::{cast_lanes, cast_slice};
+///
+/// let vectors = (0..3u8)
+/// 	.map(|k| Simd::from_array([0.0, 1.0, 2.0, 3.0]) + Simd::splat(f32::from(k) * 4.0))
+/// 	.collect::<Vec<Simd<f32, 4>>>();
+/// let lanes = cast_lanes::<f32, 4>(&vectors);
+/// assert_eq!(lanes, (0..12u8).map(f32::from).collect::<Vec<f32>>());
+/// assert_eq!(cast_slice::<f32, 4>(lanes), vectors);
+/// ```
+///
+/// ```should_panic
+/// #![feature(portable_simd)]
+///
+/// use core::simd::Simd;
+/// use lav::{cast_lanes, cast_slice};
+///
+/// let vectors = [Simd::<f32, 4>::splat(1.0); 2];
+/// let lanes = cast_lanes::<f32, 4>(&vectors);
+/// // Panics as the lanes are misaligned by one lane.
+/// let _ = cast_slice::<f32, 4>(&lanes[1..5]);
+/// ```
+#[must_use]
+#[inline]
+pub fn cast_slice<R: Real + Pod, const N: usize>(slice: &[R]) -> &[R::Simd<N>]
+where
+	LaneCount<N>: SupportedLaneCount,
+	R::Simd<N>: Pod,
+{
+	assert_eq!(slice.len() % N, 0, "length not a multiple of {N} lanes");
+	assert!(
+		slice.as_ptr().cast::<R::Simd<N>>().is_aligned(),
+		"misaligned to vector of {N} lanes"
+	);
+	bytemuck::cast_slice(slice)
+}
+
+/// Casts a mutable slice of lanes into a mutable slice of vectors without copying.
+///
+/// This is the mutable version of [`cast_slice()`] with the same requirements.
+///
+/// # Panics
+///
+/// Panics if the length of `slice` is not a multiple of `N` or if `slice` is misaligned.
+#[must_use]
+#[inline]
+pub fn cast_slice_mut<R: Real + Pod, const N: usize>(slice: &mut [R]) -> &mut [R::Simd<N>]
+where
+	LaneCount<N>: SupportedLaneCount,
+	R::Simd<N>: Pod,
+{
+	assert_eq!(slice.len() % N, 0, "length not a multiple of {N} lanes");
+	assert!(
+		slice.as_ptr().cast::<R::Simd<N>>().is_aligned(),
+		"misaligned to vector of {N} lanes"
+	);
+	bytemuck::cast_slice_mut(slice)
+}
+
+/// Casts a slice of vectors into a slice of their lanes without copying.
+///
+/// This is the inverse of [`cast_slice()`] and never panics as vectors are always aligned to
+/// their lanes.
+#[must_use]
+#[inline]
+pub fn cast_lanes<R: Real + Pod, const N: usize>(slice: &[R::Simd<N>]) -> &[R]
+where
+	LaneCount<N>: SupportedLaneCount,
+	R::Simd<N>: Pod,
+{
+	bytemuck::cast_slice(slice)
+}
+
+/// Casts a mutable slice of vectors into a mutable slice of their lanes without copying.
+///
+/// This is the mutable version of [`cast_lanes()`].
+#[must_use]
+#[inline]
+pub fn cast_lanes_mut<R: Real + Pod, const N: usize>(slice: &mut [R::Simd<N>]) -> &mut [R]
+where
+	LaneCount<N>: SupportedLaneCount,
+	R::Simd<N>: Pod,
+{
+	bytemuck::cast_slice_mut(slice)
+}
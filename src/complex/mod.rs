@@ -0,0 +1,536 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{ApproxEq, Real, SimdMask, SimdReal};
+use core::{
+	ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+	simd::{LaneCount, SupportedLaneCount},
+};
+
+/// Complex number of a real and an imaginary part.
+///
+/// Holds a single complex number. For batched arithmetic on `N` complex numbers at once, see
+/// [`ComplexSimd`].
+///
+/// # Examples
+///
+/// ```
+/// use lav::{ApproxEq, ApproxEqRel, Complex};
+///
+/// let i = Complex::<f32>::I;
+/// assert_eq!(i * i, Complex::new(-1.0, 0.0));
+/// let z = Complex::new(3.0f64, -4.0);
+/// assert_eq!(z.conj(), Complex::new(3.0, 4.0));
+/// assert_eq!(z.conj().conj(), z);
+/// assert_eq!(z * z.conj(), Complex::new(25.0, 0.0));
+/// for i in -8..=8 {
+/// 	let (r, theta) = (f64::from(i * i) + 0.5, f64::from(i) * 0.4);
+/// 	assert!(Complex::from_polar(r, theta)
+/// 		.norm()
+/// 		.approx_eq_rel(&r, f64::EPSILON * 2.0));
+/// }
+/// let z = Complex::new(1.0, 2.0) / Complex::new(3.0, 4.0);
+/// assert!(z.approx_eq(&Complex::new(0.44, 0.08), 0.0, 1u64));
+/// // Does not overflow unlike dividing by the squared magnitude.
+/// let z = Complex::new(1e20f32, 0.0);
+/// assert_eq!(z / z, Complex::new(1.0, 0.0));
+/// let z = Complex::new(1e-30f32, -1e30);
+/// assert_eq!(z / z, Complex::new(1.0, 0.0));
+/// ```
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Complex<R: Real> {
+	re: R,
+	im: R,
+}
+
+impl<R: Real> Complex<R> {
+	/// Imaginary unit $i$ with $i^2 = -1$.
+	pub const I: Self = Self::new(R::ZERO, R::ONE);
+
+	/// Creates complex number of real part `re` and imaginary part `im`.
+	#[must_use]
+	#[inline]
+	pub const fn new(re: R, im: R) -> Self {
+		Self { re, im }
+	}
+	/// Creates complex number of magnitude `r` and phase angle `theta` in radians.
+	#[must_use]
+	#[inline]
+	pub fn from_polar(r: R, theta: R) -> Self {
+		let (sin, cos) = theta.sin_cos();
+		Self::new(r * cos, r * sin)
+	}
+	/// Returns the real part.
+	#[must_use]
+	#[inline]
+	pub const fn re(&self) -> R {
+		self.re
+	}
+	/// Returns the imaginary part.
+	#[must_use]
+	#[inline]
+	pub const fn im(&self) -> R {
+		self.im
+	}
+	/// Returns the complex conjugate, i.e., negates the imaginary part.
+	#[must_use]
+	#[inline]
+	pub fn conj(self) -> Self {
+		Self::new(self.re, -self.im)
+	}
+	/// Computes the squared magnitude.
+	#[must_use]
+	#[inline]
+	pub fn norm_squared(self) -> R {
+		self.re.mul_add(self.re, self.im * self.im)
+	}
+	/// Computes the magnitude without undue overflow or underflow, see [`Real::hypot()`].
+	#[must_use]
+	#[inline]
+	pub fn norm(self) -> R {
+		self.re.hypot(self.im)
+	}
+	/// Computes the phase angle in radians in the interval $[-\pi, \pi]$, see [`Real::atan2()`].
+	#[must_use]
+	#[inline]
+	pub fn arg(self) -> R {
+		self.im.atan2(self.re)
+	}
+}
+
+impl<R: Real> Default for Complex<R> {
+	#[inline]
+	fn default() -> Self {
+		Self::new(R::ZERO, R::ZERO)
+	}
+}
+
+impl<R: Real> From<R> for Complex<R> {
+	#[inline]
+	fn from(re: R) -> Self {
+		Self::new(re, R::ZERO)
+	}
+}
+
+impl<R: Real> ApproxEq<R> for Complex<R> {
+	#[inline]
+	fn approx_eq(&self, other: &Self, epsilon: R, ulp: R::Bits) -> bool {
+		self.re.approx_eq(&other.re, epsilon, ulp) && self.im.approx_eq(&other.im, epsilon, ulp)
+	}
+}
+
+impl<R: Real> Add for Complex<R> {
+	type Output = Self;
+
+	#[inline]
+	fn add(self, other: Self) -> Self::Output {
+		Self::new(self.re + other.re, self.im + other.im)
+	}
+}
+
+impl<R: Real> AddAssign for Complex<R> {
+	#[inline]
+	fn add_assign(&mut self, other: Self) {
+		*self = *self + other;
+	}
+}
+
+impl<R: Real> Sub for Complex<R> {
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, other: Self) -> Self::Output {
+		Self::new(self.re - other.re, self.im - other.im)
+	}
+}
+
+impl<R: Real> SubAssign for Complex<R> {
+	#[inline]
+	fn sub_assign(&mut self, other: Self) {
+		*self = *self - other;
+	}
+}
+
+impl<R: Real> Mul for Complex<R> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: Self) -> Self::Output {
+		Self::new(
+			self.re.mul_add(other.re, -self.im * other.im),
+			self.re.mul_add(other.im, self.im * other.re),
+		)
+	}
+}
+
+impl<R: Real> MulAssign for Complex<R> {
+	#[inline]
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
+	}
+}
+
+impl<R: Real> Mul<R> for Complex<R> {
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: R) -> Self::Output {
+		Self::new(self.re * other, self.im * other)
+	}
+}
+
+impl<R: Real> MulAssign<R> for Complex<R> {
+	#[inline]
+	fn mul_assign(&mut self, other: R) {
+		*self = *self * other;
+	}
+}
+
+impl<R: Real> Div for Complex<R> {
+	type Output = Self;
+
+	/// Divides by Smith's algorithm avoiding undue overflow or underflow.
+	#[inline]
+	fn div(self, other: Self) -> Self::Output {
+		if other.re.abs() < other.im.abs() {
+			let ratio = other.re / other.im;
+			let den = other.re.mul_add(ratio, other.im);
+			Self::new(
+				self.re.mul_add(ratio, self.im) / den,
+				self.im.mul_add(ratio, -self.re) / den,
+			)
+		} else {
+			let ratio = other.im / other.re;
+			let den = other.im.mul_add(ratio, other.re);
+			Self::new(
+				self.im.mul_add(ratio, self.re) / den,
+				(-self.re).mul_add(ratio, self.im) / den,
+			)
+		}
+	}
+}
+
+impl<R: Real> DivAssign for Complex<R> {
+	#[inline]
+	fn div_assign(&mut self, other: Self) {
+		*self = *self / other;
+	}
+}
+
+impl<R: Real> Div<R> for Complex<R> {
+	type Output = Self;
+
+	#[inline]
+	fn div(self, other: R) -> Self::Output {
+		Self::new(self.re / other, self.im / other)
+	}
+}
+
+impl<R: Real> DivAssign<R> for Complex<R> {
+	#[inline]
+	fn div_assign(&mut self, other: R) {
+		*self = *self / other;
+	}
+}
+
+impl<R: Real> Neg for Complex<R> {
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self::new(-self.re, -self.im)
+	}
+}
+
+/// SIMD vector of [`Complex`] numbers for batched arithmetic on `N` complex numbers.
+///
+/// Holds the real and the imaginary parts as separate vectors of `N` lanes, i.e., as structure
+/// of arrays. Slices of it are arrays of structures of arrays where each operation processes
+/// `N` complex numbers at once.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use core::simd::Simd;
+/// use lav::{Complex, ComplexSimd};
+///
+/// let z = ComplexSimd::<f32, 4>::new(
+/// 	Simd::from_array([1e20, 1e-30, 1.0, 0.0]),
+/// 	Simd::from_array([0.0, -1e30, 2.0, 1.0]),
+/// );
+/// let one = ComplexSimd::splat(Complex::new(1.0, 0.0));
+/// assert_eq!(z / z, one);
+/// let i = ComplexSimd::<f64, 2>::splat(Complex::I);
+/// assert_eq!(i * i, -ComplexSimd::splat(Complex::new(1.0, 0.0)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ComplexSimd<R: Real, const N: usize>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	re: R::Simd<N>,
+	im: R::Simd<N>,
+}
+
+impl<R: Real, const N: usize> ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	/// Creates SIMD complex numbers of real parts `re` and imaginary parts `im`.
+	#[must_use]
+	#[inline]
+	pub const fn new(re: R::Simd<N>, im: R::Simd<N>) -> Self {
+		Self { re, im }
+	}
+	/// Creates SIMD complex numbers of magnitudes `r` and phase angles `theta` in radians.
+	#[must_use]
+	#[inline]
+	pub fn from_polar(r: R::Simd<N>, theta: R::Simd<N>) -> Self {
+		let (sin, cos) = theta.sin_cos();
+		Self::new(r * cos, r * sin)
+	}
+	/// Creates SIMD complex numbers by setting all lanes to the given complex number.
+	#[must_use]
+	#[inline]
+	pub fn splat(complex: Complex<R>) -> Self {
+		Self::new(R::Simd::splat(complex.re()), R::Simd::splat(complex.im()))
+	}
+	/// Returns the real parts.
+	#[must_use]
+	#[inline]
+	pub const fn re(&self) -> R::Simd<N> {
+		self.re
+	}
+	/// Returns the imaginary parts.
+	#[must_use]
+	#[inline]
+	pub const fn im(&self) -> R::Simd<N> {
+		self.im
+	}
+	/// Returns the complex number of lane `index`.
+	///
+	/// # Panics
+	///
+	/// Panics if `index` is out of bounds, i.e., not less than `N`.
+	#[must_use]
+	#[inline]
+	pub fn lane(&self, index: usize) -> Complex<R> {
+		Complex::new(self.re[index], self.im[index])
+	}
+	/// Returns the complex conjugate of each lane.
+	#[must_use]
+	#[inline]
+	pub fn conj(self) -> Self {
+		Self::new(self.re, -self.im)
+	}
+	/// Computes the squared magnitude of each lane.
+	#[must_use]
+	#[inline]
+	pub fn norm_squared(self) -> R::Simd<N> {
+		self.re.mul_add(self.re, self.im * self.im)
+	}
+	/// Computes the magnitude of each lane.
+	///
+	/// Unlike [`Complex::norm()`], this is the square root of [`Self::norm_squared()`] and may
+	/// overflow or underflow for extreme parts.
+	#[must_use]
+	#[inline]
+	pub fn norm(self) -> R::Simd<N> {
+		self.norm_squared().sqrt()
+	}
+	/// Computes the phase angle of each lane in radians, see [`Complex::arg()`].
+	#[must_use]
+	#[inline]
+	pub fn arg(self) -> R::Simd<N> {
+		let (re, im) = (self.re.to_array(), self.im.to_array());
+		R::Simd::from_array(core::array::from_fn(|lane| im[lane].atan2(re[lane])))
+	}
+}
+
+impl<R: Real, const N: usize> Default for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn default() -> Self {
+		Self::splat(Complex::default())
+	}
+}
+
+impl<R: Real, const N: usize> ApproxEq<R> for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn approx_eq(&self, other: &Self, epsilon: R, ulp: R::Bits) -> bool {
+		self.re.approx_eq(&other.re, epsilon, ulp) && self.im.approx_eq(&other.im, epsilon, ulp)
+	}
+}
+
+impl<R: Real, const N: usize> Add for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn add(self, other: Self) -> Self::Output {
+		Self::new(self.re + other.re, self.im + other.im)
+	}
+}
+
+impl<R: Real, const N: usize> AddAssign for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn add_assign(&mut self, other: Self) {
+		*self = *self + other;
+	}
+}
+
+impl<R: Real, const N: usize> Sub for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn sub(self, other: Self) -> Self::Output {
+		Self::new(self.re - other.re, self.im - other.im)
+	}
+}
+
+impl<R: Real, const N: usize> SubAssign for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn sub_assign(&mut self, other: Self) {
+		*self = *self - other;
+	}
+}
+
+impl<R: Real, const N: usize> Mul for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: Self) -> Self::Output {
+		Self::new(
+			self.re.mul_add(other.re, -self.im * other.im),
+			self.re.mul_add(other.im, self.im * other.re),
+		)
+	}
+}
+
+impl<R: Real, const N: usize> MulAssign for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn mul_assign(&mut self, other: Self) {
+		*self = *self * other;
+	}
+}
+
+impl<R: Real, const N: usize> Mul<R> for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn mul(self, other: R) -> Self::Output {
+		let other = R::Simd::splat(other);
+		Self::new(self.re * other, self.im * other)
+	}
+}
+
+impl<R: Real, const N: usize> MulAssign<R> for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn mul_assign(&mut self, other: R) {
+		*self = *self * other;
+	}
+}
+
+impl<R: Real, const N: usize> Div for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	/// Divides by Smith's algorithm avoiding undue overflow or underflow.
+	#[inline]
+	fn div(self, other: Self) -> Self::Output {
+		// Swaps the parts of lanes where the imaginary part of `other` is greater in magnitude.
+		let swap = other.re.abs().simd_lt(other.im.abs());
+		let (other_re, other_im) = (
+			swap.select(other.im, other.re),
+			swap.select(other.re, other.im),
+		);
+		let (self_re, self_im) = (swap.select(self.im, self.re), swap.select(self.re, self.im));
+		let ratio = other_im / other_re;
+		let den = other_im.mul_add(ratio, other_re);
+		Self::new(
+			self_im.mul_add(ratio, self_re) / den,
+			swap.negate((-self_re).mul_add(ratio, self_im) / den),
+		)
+	}
+}
+
+impl<R: Real, const N: usize> DivAssign for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn div_assign(&mut self, other: Self) {
+		*self = *self / other;
+	}
+}
+
+impl<R: Real, const N: usize> Div<R> for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn div(self, other: R) -> Self::Output {
+		let other = R::Simd::splat(other);
+		Self::new(self.re / other, self.im / other)
+	}
+}
+
+impl<R: Real, const N: usize> DivAssign<R> for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn div_assign(&mut self, other: R) {
+		*self = *self / other;
+	}
+}
+
+impl<R: Real, const N: usize> Neg for ComplexSimd<R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Output = Self;
+
+	#[inline]
+	fn neg(self) -> Self::Output {
+		Self::new(-self.re, -self.im)
+	}
+}
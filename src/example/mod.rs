@@ -15,8 +15,9 @@
 //! 	ops::{
 //! 		Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Shl, ShlAssign, Sub, SubAssign,
 //! 	},
+//! 	simd::Simd,
 //! };
-//! use lav::{swizzle, ApproxEq, Real, SimdMask, SimdReal};
+//! use lav::{swizzle, ApproxEq, ApproxEqRel, Real, SimdMask, SimdReal};
 //!
 //! #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 //! #[repr(transparent)]
@@ -41,7 +42,7 @@
 //! 		self.norm_squared().sqrt()
 //! 	}
 //! 	pub fn norm_squared(&self) -> R {
-//! 		(self.wxyz * self.wxyz).reduce_sum()
+//! 		self.wxyz.dot(self.wxyz)
 //! 	}
 //! 	pub fn unit(self) -> Self {
 //! 		self / self.norm()
@@ -154,6 +155,12 @@
 //! 	}
 //! }
 //!
+//! impl<R: Real> ApproxEqRel<R> for Rotator3<R> {
+//! 	fn approx_eq_rel(&self, other: &Self, rel: R) -> bool {
+//! 		self.wxyz.approx_eq_rel(&other.wxyz, rel)
+//! 	}
+//! }
+//!
 //! impl<R: Real> Add for Rotator3<R> {
 //! 	type Output = Self;
 //!
@@ -270,13 +277,15 @@
 //! 	pub fn as_points(points: &[R]) -> &[Self] {
 //! 		let (prefix, points, suffix) = R::as_simd::<4>(points);
 //! 		assert!(prefix.is_empty() && suffix.is_empty(), "misaligned");
-//! 		// Safe due to `#[repr(transparent)]`.
+//! 		// Safe due to `#[repr(transparent)]`. See `lav::cast_slice()` of the `bytemuck`
+//! 		// feature for casting lanes into vectors without `unsafe` code.
 //! 		unsafe { transmute::<&[R::Simd<4>], &[Point3<R>]>(points) }
 //! 	}
 //! 	pub fn as_points_mut(points: &mut [R]) -> &mut [Self] {
 //! 		let (prefix, points, suffix) = R::as_simd_mut::<4>(points);
 //! 		assert!(prefix.is_empty() && suffix.is_empty(), "misaligned");
-//! 		// Safe due to `#[repr(transparent)]`.
+//! 		// Safe due to `#[repr(transparent)]`. See `lav::cast_slice_mut()` of the `bytemuck`
+//! 		// feature for casting lanes into vectors without `unsafe` code.
 //! 		unsafe { transmute::<&mut [R::Simd<4>], &mut [Point3<R>]>(points) }
 //! 	}
 //! 	pub fn norm(&self) -> R {
@@ -329,6 +338,12 @@
 //! 		self.wXYZ[3] = Z;
 //! 		self
 //! 	}
+//! 	pub fn clamp(self, min: Self, max: Self) -> Self {
+//! 		// Clamps `w` as well, which stays unchanged for points of equal `w`, e.g., unit points.
+//! 		Self {
+//! 			wXYZ: self.wXYZ.simd_clamp(min.wXYZ, max.wXYZ),
+//! 		}
+//! 	}
 //! }
 //!
 //! impl<R: Real> Default for Point3<R> {
@@ -355,6 +370,12 @@
 //! 	}
 //! }
 //!
+//! impl<R: Real> ApproxEqRel<R> for Point3<R> {
+//! 	fn approx_eq_rel(&self, other: &Self, rel: R) -> bool {
+//! 		self.wXYZ.approx_eq_rel(&other.wXYZ, rel)
+//! 	}
+//! }
+//!
 //! impl<R: Real> Add for Point3<R> {
 //! 	type Output = Self;
 //!
@@ -442,6 +463,11 @@
 //! 	}
 //! }
 //!
+//! pub fn rotate_2d<R: Real>(angle: R, xy: R::Simd<2>) -> R::Simd<2> {
+//! 	let [x, y] = R::Simd::rotation_2d(R::Simd::splat(angle));
+//! 	R::Simd::from_array([x.dot(xy), y.dot(xy)])
+//! }
+//!
 //! let r000_ = Rotator3::default();
 //! let r030x = Rotator3::new(030f64.to_radians(), 1.0, 0.0, 0.0);
 //! let r060x = Rotator3::new(060f64.to_radians(), 1.0, 0.0, 0.0);
@@ -452,10 +478,26 @@
 //! assert!((r030x * r030x.rev()).approx_eq(&Rotator3::default(), f64::EPSILON, 0));
 //! assert!(r330x.constrain().approx_eq(&r030x.rev(), 0.0, 5));
 //!
+//! let r030x_e9 = r030x * 1e9;
+//! assert!((r030x_e9 * r030x_e9).approx_ne(&(r060x * 1e18), f64::EPSILON, 0));
+//! assert!((r030x_e9 * r030x_e9).approx_eq_rel(&(r060x * 1e18), f64::EPSILON));
+//!
 //! let r090x = Rotator3::new(090f64.to_radians(), 1.0, 0.0, 0.0);
 //! let x5 = Point3::new(1.0, 5.0, 0.0, 0.0);
 //! let y5 = Point3::new(1.0, 0.0, 5.0, 0.0);
 //! let z5 = Point3::new(1.0, 0.0, 0.0, 5.0);
 //! assert!((x5 << r090x).approx_eq(&x5, 0.0, 0));
 //! assert!((y5 << r090x).approx_eq(&z5, 5.0 * f64::EPSILON, 0));
+//!
+//! let min = Point3::new(1.0, -1.0, -1.0, -1.0);
+//! let max = Point3::new(1.0, 1.0, 1.0, 1.0);
+//! let p = Point3::new(1.0, 5.0, -0.5, -5.0);
+//! assert_eq!(p.clamp(min, max), Point3::new(1.0, 1.0, -0.5, -1.0));
+//!
+//! let x1 = Simd::from_array([1.0, 0.0]);
+//! let y1 = Simd::from_array([0.0, 1.0]);
+//! let xy = Simd::from_array([3.0, 4.0]);
+//! assert!(rotate_2d(090f64.to_radians(), x1).approx_eq(&y1, f64::EPSILON, 0));
+//! let r030 = rotate_2d(030f64.to_radians(), xy);
+//! assert!(rotate_2d(-030f64.to_radians(), r030).approx_eq(&xy, 0.0, 1));
 //! ```
@@ -0,0 +1,49 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Target features of the current build target.
+//!
+//! Wraps [`CURRENT_TARGET`] to choose between algorithm variants, e.g., whether to prefer
+//! [`Real::mul_add`] over an unfused multiply-add.
+
+use super::Real;
+use target_features::{Architecture, Feature, CURRENT_TARGET};
+
+/// Returns whether the current build target supports the target `feature` of its architecture.
+///
+/// Returns `false` if `feature` is unknown to the architecture.
+#[must_use]
+pub const fn has(feature: &str) -> bool {
+	match Feature::new(CURRENT_TARGET.architecture(), feature) {
+		Ok(feature) => CURRENT_TARGET.supports_feature(feature),
+		Err(_) => false,
+	}
+}
+
+/// Returns whether the current build target supports fused multiply-add instructions.
+#[must_use]
+pub const fn has_fma() -> bool {
+	match CURRENT_TARGET.architecture() {
+		Architecture::X86 => has("fma"),
+		Architecture::AArch64 => has("neon"),
+		Architecture::Arm => has("vfp4"),
+		_ => false,
+	}
+}
+
+/// Returns whether the current build target supports AVX2 instructions.
+#[must_use]
+pub const fn has_avx2() -> bool {
+	has("avx2")
+}
+
+/// Returns the suggested number of SIMD vector lanes of `R` for the current build target.
+///
+/// This is [`Real::NATIVE_LANE_COUNT`], i.e., `1` if unknown.
+#[must_use]
+pub const fn suggested_lanes<R: Real>() -> usize {
+	R::NATIVE_LANE_COUNT
+}
@@ -20,16 +20,28 @@
 //!     GAT is generic over the number of SIMD vector lanes `N`.
 //!   * [AoS/SoA/AoSoA] via [`Real::as_simd`]/[`Real::as_simd_mut`] abstracting over
 //!     [`as_simd`]/[`as_simd_mut`] of [`f32`] and [`f64`] slices.
+//!   * [`SimdSlice`] iterating over the SIMD vectors of a slice followed by a scalar tail.
 //!   * Lanewise approximate equality test wrt to epsilon and [ULP] SIMD vectors.
 //!   * [`ApproxEq`] trait complementing [`PartialEq`].
+//!   * [`Accumulator`] of lane-parallel partial sums reducing them horizontally only once.
+//!   * [`Welford`] running statistics of streaming samples pushed one by one or per SIMD vector.
+//!   * [`Dual`] and [`SimdDual`] numbers for forward-mode automatic differentiation.
+//!   * [`RealLike`] trait generic over [`Real`] and [`Dual`] numbers.
+//!   * [`Complex`] and [`ComplexSimd`] numbers for complex arithmetic.
 //!
 //! # Optional Features
 //!
 //! Following features are disabled by default unless their feature gate is enabled:
 //!
 //!   * [`target-features`]: Provides native number of SIMD vector lanes
-//!     `Real::NATIVE_LANE_COUNT` for the current build target.
+//!     `Real::NATIVE_LANE_COUNT` and target features via `features` for the current build
+//!     target.
 //!   * [`libm`]: Enables [`no_std`] without loss of functionality.
+//!   * [`serde`]: Provides (de)serialization of SIMD vectors as arrays of their lanes via
+//!     `serde` module.
+//!   * [`bytemuck`]: Implements `Pod` and `Zeroable` for SIMD vectors and provides
+//!     `cast_slice()` casting lanes into vectors without `unsafe` code.
+//!   * [`rand`]: Provides `random_simd()` and `random_bits()` sampling SIMD vectors lane by lane.
 //!
 //! [Portable SIMD]: `core::simd`
 //! [`Simd<f32, N>`]: `core::simd::Simd`
@@ -39,6 +51,9 @@
 //! [`as_simd_mut`]: `slice::as_simd_mut`
 //! [`target-features`]: https://docs.rs/target-features
 //! [`libm`]: https://docs.rs/libm
+//! [`serde`]: https://docs.rs/serde
+//! [`bytemuck`]: https://docs.rs/bytemuck
+//! [`rand`]: https://docs.rs/rand
 //! [`no_std`]: https://docs.rust-embedded.org/book/intro/no-std.html
 //! [AoS/SoA/AoSoA]: https://en.wikipedia.org/wiki/AoS_and_SoA
 //! [ULP]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
@@ -46,22 +61,48 @@
 #![feature(decl_macro)]
 #![feature(portable_simd)]
 #![feature(convert_float_to_int)]
+#![cfg_attr(not(feature = "libm"), feature(float_gamma))]
+#![cfg_attr(not(feature = "libm"), feature(float_erf))]
 #![feature(doc_auto_cfg)]
 #![cfg_attr(feature = "libm", no_std)]
 
+use core::{error::Error, fmt, num::ParseFloatError};
+
+mod accum;
+mod autodiff;
 mod bits;
+#[cfg(feature = "bytemuck")]
+mod cast;
+mod complex;
+#[cfg(feature = "rand")]
+mod random;
 mod real;
 mod simd_bits;
 mod simd_mask;
 mod simd_real;
+mod slice;
+mod stats;
 
+pub use accum::*;
+pub use autodiff::*;
 pub use bits::*;
+#[cfg(feature = "bytemuck")]
+pub use cast::*;
+pub use complex::*;
+#[cfg(feature = "rand")]
+pub use random::*;
 pub use real::*;
 pub use simd_bits::*;
 pub use simd_mask::*;
 pub use simd_real::*;
+pub use slice::*;
+pub use stats::*;
 
 pub mod example;
+#[cfg(feature = "target-features")]
+pub mod features;
+#[cfg(feature = "serde")]
+pub mod serde;
 
 /// Selects lanes from two vectors by mask vector.
 pub trait Select<Mask> {
@@ -73,7 +114,74 @@ pub trait Select<Mask> {
 	fn select(mask: Mask, true_values: Self, false_values: Self) -> Self;
 }
 
+/// Rounding mode selecting the integer a number is rounded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+	/// Rounds to the nearest integer. Rounds half-way cases away from `0.0`.
+	Nearest,
+	/// Rounds to the nearest integer. Rounds half-way cases to the even integer.
+	TiesEven,
+	/// Rounds toward `0.0`, i.e., truncates the fractional part.
+	TowardZero,
+	/// Rounds toward $-\infty$, i.e., to the largest integer less than or equal to a number.
+	Down,
+	/// Rounds toward $+\infty$, i.e., to the smallest integer greater than or equal to a number.
+	Up,
+}
+
+/// An error which can be returned when parsing a finite [`Real`].
+///
+/// Returned by [`Real::parse_finite()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseRealError {
+	/// The string is not a valid floating-point number as per [`FromStr`].
+	///
+	/// [`FromStr`]: `core::str::FromStr`
+	Invalid(ParseFloatError),
+	/// The string is a valid floating-point number but either infinite or NaN.
+	NonFinite,
+}
+
+impl From<ParseFloatError> for ParseRealError {
+	#[inline]
+	fn from(error: ParseFloatError) -> Self {
+		Self::Invalid(error)
+	}
+}
+
+impl fmt::Display for ParseRealError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Invalid(error) => error.fmt(f),
+			Self::NonFinite => f.write_str("infinite or NaN floating-point number"),
+		}
+	}
+}
+
+impl Error for ParseRealError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			Self::Invalid(error) => Some(error),
+			Self::NonFinite => None,
+		}
+	}
+}
+
 /// Tests for approximate equality.
+///
+/// # Examples
+///
+/// ```
+/// use lav::ApproxEq;
+///
+/// // Numbers of opposite signs are never equal within ULPs.
+/// assert!(!(-1.0e-30f32).approx_eq(&1.0e-30, 0.0, 10));
+/// assert!(!(-1.0e-30f64).approx_eq(&1.0e-30, 0.0, 10));
+/// assert!(1.0e-30f32.approx_eq(&1.0e-30, 0.0, 10));
+/// assert!(1.0e-30f64.approx_eq(&1.0e-30, 0.0, 10));
+/// // Unless they are within `epsilon`.
+/// assert!((-1.0e-30f32).approx_eq(&1.0e-30, f32::EPSILON, 0));
+/// ```
 pub trait ApproxEq<R: Real, Rhs = Self>
 where
 	Rhs: ?Sized,
@@ -88,3 +196,53 @@ where
 		!self.approx_eq(other, epsilon, ulp)
 	}
 }
+
+/// Tests for approximate equality wrt a relative tolerance.
+///
+/// Unlike [`ApproxEq`], the tolerance scales with the magnitude of the operands, i.e., numbers
+/// are equal if `|a - b| <= rel * max(|a|, |b|)`. Hence, zero is only equal to zero whereas
+/// infinities are only equal to infinities of the same sign. NaN is never equal.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use core::simd::Simd;
+/// use lav::{ApproxEq, ApproxEqRel};
+///
+/// // Large magnitudes differing by 1 ppm are not within an absolute `epsilon`.
+/// assert!(1.0e20f64.approx_ne(&1.000_001e20, f64::EPSILON, 0));
+/// // But within a relative tolerance.
+/// assert!(1.0e20f64.approx_eq_rel(&1.000_001e20, 1.0e-5));
+/// assert!(1.0e20f64.approx_ne_rel(&1.000_001e20, 1.0e-7));
+/// assert!((-3.0e30f32).approx_eq_rel(&-3.000_003e30, 1.0e-5));
+///
+/// let a = Simd::from_array([1.0e20f64, -2.0e30, 3.0e-30, 4.0e200]);
+/// let b = a * Simd::splat(1.0 + 1.0e-6);
+/// assert!(a.approx_ne(&b, f64::EPSILON, 0));
+/// assert!(a.approx_eq_rel(&b, 1.0e-5));
+/// assert!(a.approx_ne_rel(&b, 1.0e-7));
+///
+/// // Zero is only equal to zero, infinity only to infinity of the same sign, NaN to nothing.
+/// assert!(0.0f64.approx_eq_rel(&-0.0, 0.0));
+/// assert!(0.0f64.approx_ne_rel(&f64::MIN_POSITIVE, 0.5));
+/// assert!(f64::INFINITY.approx_eq_rel(&f64::INFINITY, 0.0));
+/// assert!(f64::INFINITY.approx_ne_rel(&f64::NEG_INFINITY, 1.0));
+/// assert!(f64::INFINITY.approx_ne_rel(&f64::MAX, 1.0));
+/// assert!(f64::NAN.approx_ne_rel(&f64::NAN, 1.0));
+/// ```
+pub trait ApproxEqRel<R: Real, Rhs = Self>
+where
+	Rhs: ?Sized,
+{
+	/// Tests for approximate equality wrt the relative tolerance `rel`.
+	#[must_use]
+	fn approx_eq_rel(&self, other: &Rhs, rel: R) -> bool;
+	/// Tests for approximate inequality wrt the relative tolerance `rel`.
+	#[must_use]
+	#[inline]
+	fn approx_ne_rel(&self, other: &Rhs, rel: R) -> bool {
+		!self.approx_eq_rel(other, rel)
+	}
+}
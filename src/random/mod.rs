@@ -0,0 +1,85 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{Bits, Real, SimdReal};
+use core::{
+	array,
+	simd::{LaneCount, SupportedLaneCount},
+};
+use rand::{
+	distributions::{Distribution, Standard},
+	Rng,
+};
+
+/// Samples a SIMD vector of `N` lanes from the [`Standard`] distribution of `R`.
+///
+/// Each lane is sampled independently from the half-open interval $[0, 1)$ in lane order.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use core::simd::Simd;
+/// use lav::{random_simd, SimdReal};
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let mut rng = SmallRng::seed_from_u64(42);
+/// let mut sum = Simd::splat(0.0);
+/// for _ in 0..10_000 {
+/// 	let x = random_simd::<f64, _, 4>(&mut rng);
+/// 	assert!(x.to_array().iter().all(|x| (0.0..1.0).contains(x)));
+/// 	sum += x;
+/// }
+/// let mean = sum / Simd::splat(10_000.0);
+/// assert!(mean.to_array().iter().all(|mean| (mean - 0.5).abs() < 0.01));
+/// let x = random_simd::<f32, _, 8>(&mut rng);
+/// assert!(x.to_array().iter().all(|x| (0.0..1.0).contains(x)));
+/// ```
+#[must_use]
+#[inline]
+pub fn random_simd<R: Real, G: Rng + ?Sized, const N: usize>(rng: &mut G) -> R::Simd<N>
+where
+	LaneCount<N>: SupportedLaneCount,
+	Standard: Distribution<R>,
+{
+	R::Simd::from_array(array::from_fn(|_lane| rng.gen()))
+}
+
+/// Samples a SIMD vector of `N` lanes of uniformly distributed bits.
+///
+/// Each lane is sampled independently from the [`Standard`] distribution of `B` in lane order,
+/// i.e., every bit pattern is equally likely.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use lav::random_bits;
+/// use rand::{rngs::SmallRng, SeedableRng};
+///
+/// let mut rng = SmallRng::seed_from_u64(42);
+/// let mut ones = 0;
+/// for _ in 0..1_000 {
+/// 	ones += random_bits::<u64, _, 4>(&mut rng)
+/// 		.to_array()
+/// 		.map(u64::count_ones)
+/// 		.iter()
+/// 		.sum::<u32>();
+/// }
+/// // Every bit is set with a probability of one half.
+/// assert!((f64::from(ones) / 256_000.0 - 0.5).abs() < 0.01);
+/// ```
+#[must_use]
+#[inline]
+pub fn random_bits<B: Bits, G: Rng + ?Sized, const N: usize>(rng: &mut G) -> B::Simd<N>
+where
+	LaneCount<N>: SupportedLaneCount,
+	Standard: Distribution<B>,
+{
+	B::Simd::from(array::from_fn(|_lane| rng.gen()))
+}
@@ -29,6 +29,7 @@ impl Real for f64 {
 
 	const ZERO: Self = 0.0;
 	const ONE: Self = 1.0;
+	const NEG_ONE: Self = -1.0;
 	const TWO: Self = 2.0;
 
 	const PI: Self = core::f64::consts::PI;
@@ -53,6 +54,13 @@ impl Real for f64 {
 	const FRAC_2_PI: Self = core::f64::consts::FRAC_2_PI;
 	const FRAC_2_SQRT_PI: Self = core::f64::consts::FRAC_2_SQRT_PI;
 
+	const LOG2_E: Self = core::f64::consts::LOG2_E;
+	const LOG10_E: Self = core::f64::consts::LOG10_E;
+	const LN_2: Self = core::f64::consts::LN_2;
+	const LN_10: Self = core::f64::consts::LN_10;
+	const LN_2_HI: Self = 6.931_471_803_691_238e-1;
+	const LN_2_LO: Self = 1.908_214_929_270_587_7e-10;
+
 	const EPSILON: Self = Self::EPSILON;
 	const SQRT_EPSILON: Self = 0.000_000_014_901_161_193_847_656;
 	const CBRT_EPSILON: Self = 0.000_006_055_454_452_393_339_5;
@@ -81,6 +89,53 @@ impl Real for f64 {
 		self.to_bits()
 	}
 
+	#[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+	#[inline]
+	fn decompose(self) -> (bool, i32, Self::Bits) {
+		let bits = self.to_bits();
+		let sign = bits >> 63 != 0;
+		let exponent = (bits >> 52 & 0x7ff) as i32;
+		let mantissa = bits & 0x000f_ffff_ffff_ffff;
+		match exponent {
+			0 => (sign, Self::MIN_EXP - 1, mantissa),
+			0x7ff => (sign, Self::MAX_EXP, mantissa),
+			_ => (sign, exponent - 1023, mantissa | 0x0010_0000_0000_0000),
+		}
+	}
+	#[allow(clippy::cast_sign_loss)]
+	#[inline]
+	fn recompose(sign: bool, exponent: i32, mantissa: Self::Bits) -> Self {
+		let exponent = if exponent == Self::MAX_EXP {
+			0x7ff
+		} else if mantissa & 0x0010_0000_0000_0000 == 0 {
+			0
+		} else {
+			(exponent + 1023) as u64 & 0x7ff
+		};
+		Self::from_bits(u64::from(sign) << 63 | exponent << 52 | mantissa & 0x000f_ffff_ffff_ffff)
+	}
+	#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+	#[inline]
+	fn to_fixed(self, frac_bits: u32) -> Self::Bits {
+		assert!(frac_bits <= 64, "too many fractional bits");
+		Real::round(self * Self::from_bits((1023 + u64::from(frac_bits)) << 52)) as u64
+	}
+	#[allow(clippy::cast_precision_loss)]
+	#[inline]
+	fn from_fixed(bits: Self::Bits, frac_bits: u32) -> Self {
+		assert!(frac_bits <= 64, "too many fractional bits");
+		bits as Self * Self::from_bits((1023 - u64::from(frac_bits)) << 52)
+	}
+	#[allow(clippy::cast_possible_truncation)]
+	#[inline]
+	fn narrow_saturating(self) -> f32 {
+		if self.is_finite() {
+			self.clamp(f32::MIN.into(), f32::MAX.into()) as f32
+		} else {
+			self as f32
+		}
+	}
+
 	#[inline]
 	fn is_sign_positive(self) -> bool {
 		self.is_sign_positive()
@@ -146,6 +201,16 @@ impl Real for f64 {
 	}
 	#[cfg(feature = "libm")]
 	#[inline]
+	fn round_ties_even(self) -> Self {
+		libm::rint(self)
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn round_ties_even(self) -> Self {
+		self.round_ties_even()
+	}
+	#[cfg(feature = "libm")]
+	#[inline]
 	fn trunc(self) -> Self {
 		libm::trunc(self)
 	}
@@ -267,6 +332,28 @@ impl Real for f64 {
 		self.rem_euclid(rhs)
 	}
 
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn powi(self, n: i32) -> Self {
+		let (mut base, mut exp, mut pow) = (self, n.unsigned_abs(), 1.0);
+		while exp != 0 {
+			if exp & 1 == 1 {
+				pow *= base;
+			}
+			base *= base;
+			exp >>= 1;
+		}
+		if n < 0 {
+			pow.recip()
+		} else {
+			pow
+		}
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn powi(self, n: i32) -> Self {
+		self.powi(n)
+	}
 	#[cfg(feature = "libm")]
 	#[inline]
 	fn powf(self, n: Self) -> Self {
@@ -368,6 +455,25 @@ impl Real for f64 {
 	fn sqrt(self) -> Self {
 		self.sqrt()
 	}
+	#[inline]
+	fn rsqrt_estimate(self) -> Self {
+		if self.is_normal() && self.is_sign_positive() {
+			Self::from_bits(0x5fe6_eb50_c7b5_37a9 - (self.to_bits() >> 1))
+		} else {
+			self.recip_sqrt()
+		}
+	}
+	#[inline]
+	fn rsqrt_accurate(self) -> Self {
+		let mut rsqrt = self.rsqrt_refined();
+		if self.is_normal() && self.is_sign_positive() {
+			for _ in 0..3 {
+				let error = Real::mul_add(-self * rsqrt, rsqrt, 1.0);
+				rsqrt = Real::mul_add(0.5 * rsqrt, error, rsqrt);
+			}
+		}
+		rsqrt
+	}
 	#[cfg(feature = "libm")]
 	#[inline]
 	fn cbrt(self) -> Self {
@@ -521,8 +627,98 @@ impl Real for f64 {
 		self.atan2(other)
 	}
 
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn gamma(self) -> Self {
+		libm::tgamma(self)
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn gamma(self) -> Self {
+		self.gamma()
+	}
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn ln_gamma(self) -> Self {
+		libm::lgamma(self)
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn ln_gamma(self) -> Self {
+		self.ln_gamma().0
+	}
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn erf(self) -> Self {
+		libm::erf(self)
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn erf(self) -> Self {
+		self.erf()
+	}
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn erfc(self) -> Self {
+		libm::erfc(self)
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn erfc(self) -> Self {
+		self.erfc()
+	}
+
 	#[inline]
 	fn total_cmp(&self, other: &Self) -> Ordering {
 		self.total_cmp(other)
 	}
+	#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+	#[inline]
+	fn signed_ulps(self, other: Self) -> i64 {
+		if self.is_nan() || other.is_nan() {
+			return match self.total_cmp(&other) {
+				Ordering::Less => i64::MIN,
+				Ordering::Equal => 0,
+				Ordering::Greater => i64::MAX,
+			};
+		}
+		let ordered = |real: Self| {
+			let bits = real.to_bits() as i64;
+			bits ^ (((bits >> 63) as u64) >> 1) as i64
+		};
+		ordered(self).saturating_sub(ordered(other))
+	}
+
+	#[inline]
+	fn factorial(n: u32) -> Self {
+		let mut factorial = 1.0;
+		for k in 2..=n {
+			factorial *= Self::from(k);
+			if factorial.is_infinite() {
+				break;
+			}
+		}
+		factorial
+	}
+	#[inline]
+	fn binomial(n: u32, k: u32) -> Self {
+		if k > n {
+			return 0.0;
+		}
+		let k = k.min(n - k);
+		let mut binomial = 1.0;
+		for i in 1..=k {
+			let (numerator, denominator) = (Self::from(n - k + i), Self::from(i));
+			let product = binomial * numerator;
+			binomial = if product.is_finite() {
+				product / denominator
+			} else {
+				binomial / denominator * numerator
+			};
+			if binomial.is_infinite() {
+				break;
+			}
+		}
+		binomial
+	}
 }
@@ -6,7 +6,7 @@
 
 // Derivative work of `core::{f32, f64}` licensed under `MIT OR Apache-2.0`.
 
-use super::{ApproxEq, Bits, SimdReal};
+use super::{ApproxEq, ApproxEqRel, Bits, ParseRealError, RoundMode, SimdReal};
 use core::{
 	cmp::Ordering,
 	convert::FloatToInt,
@@ -26,7 +26,7 @@ mod f64;
 pub trait Real
 where
 	Self: Send + Sync + Clone + Copy + Default,
-	Self: ApproxEq<Self, Self> + PartialEq + PartialOrd,
+	Self: ApproxEq<Self, Self> + ApproxEqRel<Self, Self> + PartialEq + PartialOrd,
 	Self: From<u8> + From<i8>,
 	Self: From<u16> + From<i16>,
 	Self: FromStr<Err = ParseFloatError>,
@@ -60,6 +60,15 @@ where
 		LaneCount<N>: SupportedLaneCount;
 
 	/// Native lane count of current build target or `1` if unknown.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// assert!(<f32 as Real>::NATIVE_LANE_COUNT.is_power_of_two());
+	/// assert!(<f64 as Real>::NATIVE_LANE_COUNT.is_power_of_two());
+	/// ```
 	#[cfg(feature = "target-features")]
 	const NATIVE_LANE_COUNT: usize;
 
@@ -67,6 +76,8 @@ where
 	const ZERO: Self;
 	/// $1$
 	const ONE: Self;
+	/// $-1$
+	const NEG_ONE: Self;
 	/// $2$
 	const TWO: Self;
 
@@ -110,6 +121,49 @@ where
 	/// $\frac{2}{\sqrt{\pi}}$
 	const FRAC_2_SQRT_PI: Self;
 
+	/// $\log_{2} e$
+	const LOG2_E: Self;
+	/// $\log_{10} e$
+	const LOG10_E: Self;
+	/// $\ln 2$
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::{ApproxEq, Bits, Real};
+	///
+	/// const LN_2: f64 = f64::LN_2;
+	/// const NEG_LN_10: f32 = f32::NEG_ONE * f32::LN_10;
+	/// const LOG2_E: f64 = f64::LOG2_E;
+	/// const _: f32 = f32::LOG10_E;
+	///
+	/// fn check<R: Real>() {
+	/// 	assert!(R::LN_2
+	/// 		.exp()
+	/// 		.approx_eq(&(R::ONE + R::ONE), R::ZERO, R::Bits::ONE));
+	/// 	assert!(R::LN_10
+	/// 		.exp()
+	/// 		.approx_eq(&R::from(10u8), R::ZERO, R::Bits::ONE));
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// assert_eq!(LN_2 * LOG2_E, 1.0);
+	/// assert_eq!(NEG_LN_10, -core::f32::consts::LN_10);
+	/// ```
+	const LN_2: Self;
+	/// $\ln 10$
+	const LN_10: Self;
+	/// High part of $\ln 2$ split into `LN_2_HI + LN_2_LO` for accurate range reduction.
+	///
+	/// Its trailing 9 (`f32`) or 21 (`f64`) mantissa bits are zero such that `k * LN_2_HI` is exact
+	/// for the integer exponents `k` occurring in `exp`/`ln` range reduction.
+	const LN_2_HI: Self;
+	/// Low part of $\ln 2$ split into `LN_2_HI + LN_2_LO` for accurate range reduction.
+	///
+	/// It is $\ln 2$ minus [`Self::LN_2_HI`] rounded to nearest.
+	const LN_2_LO: Self;
+
 	/// [Machine epsilon] $\epsilon$ of floating-point type.
 	///
 	/// [Machine epsilon]: https://en.wikipedia.org/wiki/Machine_epsilon
@@ -189,6 +243,74 @@ where
 	#[must_use]
 	fn to_bits(self) -> Self::Bits;
 
+	/// Decomposes the number into its sign, unbiased exponent, and mantissa.
+	///
+	/// Returns `(sign, exponent, mantissa)` extracted from [`Self::to_bits()`] where `sign` is
+	/// `true` if the sign bit is set. The `mantissa` is the significand including the implicit
+	/// leading bit, such that finite numbers equal $\pm m \cdot 2^{e - p + 1}$ with
+	/// $p =$ [`Self::MANTISSA_DIGITS`]. Following IEEE 754 conventions, the exponent is:
+	///
+	///   * in the range `Self::MIN_EXP - 1..Self::MAX_EXP` for normal numbers whose mantissa has
+	///     its leading bit set,
+	///   * `Self::MIN_EXP - 1` for subnormal numbers and zeros whose mantissa has its leading bit
+	///     unset (but is zero for zeros only),
+	///   * [`Self::MAX_EXP`] for infinities whose mantissa is zero and NaNs whose mantissa is the
+	///     nonzero payload.
+	#[must_use]
+	fn decompose(self) -> (bool, i32, Self::Bits);
+	/// Composes a number from its sign, unbiased exponent, and mantissa.
+	///
+	/// This is the inverse of [`Self::decompose()`], the result is unspecified for combinations not
+	/// returned by it.
+	#[must_use]
+	fn recompose(sign: bool, exponent: i32, mantissa: Self::Bits) -> Self;
+	/// Converts to unsigned fixed-point with `frac_bits` fractional bits.
+	///
+	/// Rounds `self * 2^frac_bits` to the nearest integer with ties away from zero and saturates
+	/// it to the range of [`Self::Bits`], i.e., negative numbers result in zero and numbers too
+	/// large in magnitude result in its maximum. NaN results in zero.
+	///
+	/// # Panics
+	///
+	/// Panics if `frac_bits` exceeds the number of bits of [`Self::Bits`].
+	#[must_use]
+	fn to_fixed(self, frac_bits: u32) -> Self::Bits;
+	/// Converts from unsigned fixed-point `bits` with `frac_bits` fractional bits.
+	///
+	/// Computes `bits * 2^-frac_bits` where `bits` is rounded to the nearest number with ties to
+	/// even if it has more significant bits than [`Self::MANTISSA_DIGITS`].
+	///
+	/// # Panics
+	///
+	/// Panics if `frac_bits` exceeds the number of bits of [`Self::Bits`].
+	#[must_use]
+	fn from_fixed(bits: Self::Bits, frac_bits: u32) -> Self;
+	/// Converts to [`prim@f32`] saturating finite numbers out of its range.
+	///
+	/// Unlike the `as`-cast which rounds finite numbers beyond [`f32::MAX`] in magnitude to
+	/// infinity, this clamps them to [`f32::MIN`] or [`f32::MAX`] before rounding to nearest.
+	/// Infinities remain infinite and NaN remains NaN. This is the identity for [`prim@f32`].
+	#[must_use]
+	fn narrow_saturating(self) -> f32;
+	/// Parses a finite number from a string.
+	///
+	/// Parses like [`FromStr`] but rejects infinities and NaN, e.g., `"inf"` and `"nan"`, as well
+	/// as decimal numbers overflowing to infinity.
+	///
+	/// # Errors
+	///
+	/// Returns [`ParseRealError::Invalid`] if `s` is not a valid number and
+	/// [`ParseRealError::NonFinite`] if it is infinite or NaN.
+	#[inline]
+	fn parse_finite(s: &str) -> Result<Self, ParseRealError> {
+		let value = s.parse::<Self>()?;
+		if value.is_finite() {
+			Ok(value)
+		} else {
+			Err(ParseRealError::NonFinite)
+		}
+	}
+
 	/// Returns `true` for each lane if it has a positive sign, including `+0.0`, NaNs with positive
 	/// sign bit and positive infinity.
 	#[must_use]
@@ -213,6 +335,17 @@ where
 	/// NaN.
 	#[must_use]
 	fn is_normal(self) -> bool;
+	/// Returns `true` if `self` is a normal number, i.e., a finite nonzero number that is not
+	/// subnormal.
+	///
+	/// Equivalent to `self.classify() == FpCategory::Normal` and [`Self::is_normal()`] but
+	/// implemented as a single range check. Returns `false` for zero and subnormals as for infinity
+	/// and NaN.
+	#[must_use]
+	#[inline]
+	fn is_regular(self) -> bool {
+		(Self::MIN_POSITIVE..=Self::MAX).contains(&self.abs())
+	}
 	/// Returns the floating point category of the number.
 	///
 	/// If only one property is going to be tested, it is generally faster to use the specific
@@ -229,12 +362,27 @@ where
 	/// Returns the nearest integer to a number. Round half-way cases away from `0.0`.
 	#[must_use]
 	fn round(self) -> Self;
+	/// Returns the nearest integer to a number. Rounds half-way cases to the even integer.
+	#[must_use]
+	fn round_ties_even(self) -> Self;
 	/// Returns the integer part of a number.
 	#[must_use]
 	fn trunc(self) -> Self;
 	/// Returns the fractional part of a number.
 	#[must_use]
 	fn fract(self) -> Self;
+	/// Returns the integer a number is rounded to according to `mode`.
+	#[must_use]
+	#[inline]
+	fn round_mode(self, mode: RoundMode) -> Self {
+		match mode {
+			RoundMode::Nearest => self.round(),
+			RoundMode::TiesEven => self.round_ties_even(),
+			RoundMode::TowardZero => self.trunc(),
+			RoundMode::Down => self.floor(),
+			RoundMode::Up => self.ceil(),
+		}
+	}
 
 	/// Computes the absolute value of `self`.
 	///
@@ -246,8 +394,59 @@ where
 	///  * Returns `1.0` if the number is positive, `+0.0` or [`Self::INFINITY`].
 	///  * Returns `-1.0` if the number is negative, `-0.0` or [`Self::NEG_INFINITY`].
 	///  * Returns [`Self::NAN`] if the number is NaN.
+	///
+	/// Zeros keep their sign as required by IEEE 754, see [`Self::signum_zero()`] otherwise.
 	#[must_use]
 	fn signum(self) -> Self;
+	/// Returns a number that represents the sign of `self` or `zero_sign` if `self` is zero.
+	///
+	///  * Returns `1.0` if the number is positive or [`Self::INFINITY`].
+	///  * Returns `-1.0` if the number is negative or [`Self::NEG_INFINITY`].
+	///  * Returns `zero_sign` if the number is `+0.0` or `-0.0`.
+	///  * Returns [`Self::NAN`] if the number is NaN.
+	#[must_use]
+	#[inline]
+	fn signum_or(self, zero_sign: Self) -> Self {
+		if self == Self::ZERO {
+			zero_sign
+		} else {
+			self.signum()
+		}
+	}
+	/// Returns a number that represents the sign of `self` or zero if `self` is zero.
+	///
+	///  * Returns `1.0` if the number is positive or [`Self::INFINITY`].
+	///  * Returns `-1.0` if the number is negative or [`Self::NEG_INFINITY`].
+	///  * Returns `0.0` if the number is `+0.0` or `-0.0`.
+	///  * Returns [`Self::NAN`] if the number is NaN.
+	///
+	/// Unlike [`Self::signum()`], maps both zeros to `0.0` like the mathematical sign function.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// fn check<R: Real>() {
+	/// 	let three = R::from(3u8);
+	/// 	assert_eq!(R::ZERO.signum_zero().to_bits(), R::ZERO.to_bits());
+	/// 	assert_eq!((-R::ZERO).signum_zero().to_bits(), R::ZERO.to_bits());
+	/// 	assert_eq!(three.signum_zero(), R::ONE);
+	/// 	assert_eq!((-three).signum_zero(), -R::ONE);
+	/// 	assert_eq!(R::INFINITY.signum_zero(), R::ONE);
+	/// 	assert_eq!(R::NEG_INFINITY.signum_zero(), -R::ONE);
+	/// 	assert!(R::NAN.signum_zero().is_nan());
+	/// 	assert_eq!((-R::ZERO).signum(), -R::ONE);
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[must_use]
+	#[inline]
+	fn signum_zero(self) -> Self {
+		self.signum_or(Self::ZERO)
+	}
 	/// Returns a number composed of the magnitude of `self` and the sign of `sign`.
 	///
 	/// Equal to `self` if the sign of `self` and `sign` are the same, otherwise equal to `-self`.
@@ -255,6 +454,50 @@ where
 	/// If `self` is NaN, then NaN with the sign of `sign` is returned.
 	#[must_use]
 	fn copysign(self, sign: Self) -> Self;
+	/// Returns a number with the sign of `self` and the magnitude of `magnitude`.
+	///
+	/// Equals `magnitude.copysign(self)` with reversed argument order. The sign of NaN is used as
+	/// well.
+	#[must_use]
+	#[inline]
+	fn with_magnitude(self, magnitude: Self) -> Self {
+		magnitude.copysign(self)
+	}
+	/// Flips the sign of `self`.
+	///
+	/// Equals `-self` but expresses the intent. Flips the sign bit of signed zeros and NaNs as
+	/// well, i.e., `0.0` becomes `-0.0` and vice versa.
+	#[must_use]
+	#[inline]
+	fn flip_sign(self) -> Self {
+		-self
+	}
+	/// Returns a number composed of the magnitude of `self` and the sign given by `negative`.
+	///
+	/// Returns `-self.abs()` if `negative` is true, otherwise `self.abs()`. Signed zeros and NaNs
+	/// are treated as any other number, i.e., `0.0.with_sign(true)` is `-0.0`.
+	#[must_use]
+	#[inline]
+	fn with_sign(self, negative: bool) -> Self {
+		let abs = self.abs();
+		if negative {
+			-abs
+		} else {
+			abs
+		}
+	}
+	/// Negates `self` if `cond` is true, otherwise returns `self`.
+	///
+	/// Flips the sign bit of signed zeros and NaNs as well, see [`Self::flip_sign()`].
+	#[must_use]
+	#[inline]
+	fn negate_if(self, cond: bool) -> Self {
+		if cond {
+			-self
+		} else {
+			self
+		}
+	}
 	/// Returns the minimum of each lane.
 	///
 	/// If one of the values is NaN, then the other value is returned.
@@ -272,6 +515,47 @@ where
 	/// returns the lane in `self`.
 	#[must_use]
 	fn clamp(self, min: Self, max: Self) -> Self;
+	/// Restrict a value to a certain interval in accordance to [`Self::total_cmp()`].
+	///
+	/// Returns `max` if `self` is greater than `max` and `min` if `self` is less than `min` in
+	/// total order. Otherwise, returns `self`. Hence, `-0.0` is less than `0.0`, a positive NaN
+	/// `self` is clamped to `max` unless `max` is a positive NaN, and a negative NaN `self` is
+	/// clamped to `min` unless `min` is a negative NaN. A positive NaN `max` or negative NaN `min`
+	/// effectively leaves `self` unbounded above or below apart from other NaNs.
+	///
+	/// # Panics
+	///
+	/// Panics if `min` is greater than `max` in total order.
+	#[must_use]
+	#[inline]
+	fn clamp_total(self, min: Self, max: Self) -> Self {
+		assert!(min.total_cmp(&max).is_le(), "min > max in total order");
+		if self.total_cmp(&min).is_lt() {
+			min
+		} else if self.total_cmp(&max).is_gt() {
+			max
+		} else {
+			self
+		}
+	}
+	/// Restricts a value to the unit interval $[0, 1]$ unless it is NaN.
+	///
+	/// Equals `self.clamp(Self::ZERO, Self::ONE)`, i.e., NaN is preserved as for [`Self::clamp()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// assert_eq!((-0.5f32).saturate(), 0.0);
+	/// assert_eq!(2.0f64.saturate(), 1.0);
+	/// assert!(f32::NAN.saturate().is_nan());
+	/// ```
+	#[must_use]
+	#[inline]
+	fn saturate(self) -> Self {
+		self.clamp(Self::ZERO, Self::ONE)
+	}
 
 	/// Takes the reciprocal (inverse) of a number, `1 / self`.
 	#[must_use]
@@ -292,6 +576,29 @@ where
 	/// will be heavily dependant on designing algorithms with specific target hardware in mind.
 	#[must_use]
 	fn mul_add(self, a: Self, b: Self) -> Self;
+	/// Linearly interpolates between `self` and `other` by weight `t`.
+	///
+	/// Computes `self.mul_add(Self::ONE - t, other * t)` without clamping `t` to $[0, 1]$, i.e., it
+	/// extrapolates for `t` outside of $[0, 1]$. The endpoints are exact, i.e., `a.lerp(b, 0.0)`
+	/// equals `a` and `a.lerp(b, 1.0)` equals `b`, whereas the result is monotonic in `t` only up
+	/// to the rounding error of `Self::ONE - t`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// let (a, b) = (0.1f32, 7.3);
+	/// assert_eq!(Real::lerp(a, b, 0.0), a);
+	/// assert_eq!(Real::lerp(a, b, 1.0), b);
+	/// assert_eq!(Real::lerp(1.0f64, 3.0, 0.5), 2.0);
+	/// assert_eq!(Real::lerp(1.0f64, 3.0, 2.0), 5.0);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn lerp(self, other: Self, t: Self) -> Self {
+		self.mul_add(Self::ONE - t, other * t)
+	}
 
 	/// Calculates Euclidean division, the matching method for [`Self::rem_euclid()`].
 	///
@@ -310,6 +617,11 @@ where
 	#[must_use]
 	fn rem_euclid(self, rhs: Self) -> Self;
 
+	/// Raises a number to an integer power.
+	///
+	/// Uses binary exponentiation in case of `libm` and reciprocates for negative `n`.
+	#[must_use]
+	fn powi(self, n: i32) -> Self;
 	/// Raises a number to a floating-point power.
 	#[must_use]
 	fn powf(self, n: Self) -> Self;
@@ -329,6 +641,84 @@ where
 	/// were performed separately.
 	#[must_use]
 	fn ln_1p(self) -> Self;
+	/// Returns the natural logarithm of the sum of the exponentials of `self` and `other`,
+	/// $\ln(e^x + e^y)$.
+	///
+	/// Computes $\max(x, y) + \ln(1 + e^{-|x - y|})$ which neither overflows nor underflows
+	/// unlike the operations performed separately. Returns the other number if one number is
+	/// [`Self::NEG_INFINITY`], [`Self::INFINITY`] if any number is [`Self::INFINITY`], and NaN if
+	/// any number is NaN.
+	#[must_use]
+	#[inline]
+	fn logaddexp(self, other: Self) -> Self {
+		let max = self.max(other);
+		if self.is_nan() || other.is_nan() {
+			Self::NAN
+		} else if max.is_infinite() {
+			max
+		} else {
+			max + (-(self - other).abs()).exp().ln_1p()
+		}
+	}
+	/// Returns the logistic sigmoid function of the number, ${1 \over 1 + e^{-x}}$.
+	///
+	/// Computes ${e^x \over 1 + e^x}$ for negative $x$ such that it neither overflows nor loses
+	/// precision for large magnitudes.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::{ApproxEq, Real};
+	///
+	/// for x in [-20.0f64, -3.0, -0.5, 0.0, 0.5, 3.0, 20.0] {
+	/// 	assert!(x.sigmoid().approx_eq(&(1.0 / (1.0 + (-x).exp())), 0.0, 4));
+	/// }
+	/// for x in [-20.0f32, -3.0, -0.5, 0.0, 0.5, 3.0, 20.0] {
+	/// 	assert!(x.sigmoid().approx_eq(&(1.0 / (1.0 + (-x).exp())), 0.0, 4));
+	/// }
+	/// // The equivalent formula for negative numbers overflows to `inf / inf` for positive ones.
+	/// assert!((1000.0f64.exp() / (1.0 + 1000.0f64.exp())).is_nan());
+	/// assert_eq!(1000.0f64.sigmoid(), 1.0);
+	/// assert_eq!((-1000.0f64).sigmoid(), 0.0);
+	/// assert!((-100.0f32).sigmoid() > 0.0);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn sigmoid(self) -> Self {
+		if self >= Self::ZERO {
+			(Self::ONE + (-self).exp()).recip()
+		} else {
+			let exp = self.exp();
+			exp / (Self::ONE + exp)
+		}
+	}
+	/// Returns the softplus function of the number, $\ln(1 + e^x)$.
+	///
+	/// Computes $\max(x, 0) + \ln(1 + e^{-|x|})$ such that it neither overflows nor loses
+	/// precision for large magnitudes.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::{ApproxEq, Real};
+	///
+	/// for x in [-20.0f64, -3.0, -0.5, 0.0, 0.5, 3.0, 20.0] {
+	/// 	assert!(x.softplus().approx_eq(&x.exp().ln_1p(), 0.0, 4));
+	/// }
+	/// for x in [-20.0f32, -3.0, -0.5, 0.0, 0.5, 3.0, 20.0] {
+	/// 	assert!(x.softplus().approx_eq(&x.exp().ln_1p(), 0.0, 4));
+	/// }
+	/// // The naive formula overflows to infinity for large positive numbers.
+	/// assert!(1000.0f64.exp().ln_1p().is_infinite());
+	/// assert_eq!(1000.0f64.softplus(), 1000.0);
+	/// assert_eq!(100.0f32.softplus(), 100.0);
+	/// assert!((-1000.0f64).softplus() >= 0.0);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn softplus(self) -> Self {
+		self.max(Self::ZERO) + (-self.abs()).exp().ln_1p()
+	}
 	/// Returns the logarithm of the number with respect to an arbitrary base.
 	///
 	/// The result might not be correctly rounded owing to implementation details:
@@ -349,6 +739,43 @@ where
 	/// Returns NaN if `self` is a negative number.
 	#[must_use]
 	fn sqrt(self) -> Self;
+	/// Returns the reciprocal square root ${1 \over \sqrt{x}}$ of `self` as $x$.
+	///
+	/// Returns [`Self::INFINITY`] with the sign of `self` for $x = \pm 0$, zero for
+	/// $x = \infty$, and NaN if `self` is a negative number.
+	#[must_use]
+	#[inline]
+	fn recip_sqrt(self) -> Self {
+		self.sqrt().recip()
+	}
+	/// Estimates the reciprocal square root ${1 \over \sqrt{x}}$ of `self` as $x$ by bit
+	/// manipulation.
+	///
+	/// The relative error is less than $3.5 \cdot 10^{-2}$. Falls back to [`Self::recip_sqrt()`]
+	/// unless `self` is a positive normal number.
+	#[must_use]
+	fn rsqrt_estimate(self) -> Self;
+	/// Refines [`Self::rsqrt_estimate()`] by one Newton-Raphson step.
+	///
+	/// The relative error is less than $1.8 \cdot 10^{-3}$. Falls back to
+	/// [`Self::recip_sqrt()`] unless `self` is a positive normal number.
+	#[must_use]
+	#[inline]
+	fn rsqrt_refined(self) -> Self {
+		let rsqrt = self.rsqrt_estimate();
+		if self.is_normal() && self.is_sign_positive() {
+			rsqrt * (Self::ONE + Self::FRAC_1_2 - Self::FRAC_1_2 * self * rsqrt * rsqrt)
+		} else {
+			rsqrt
+		}
+	}
+	/// Refines [`Self::rsqrt_refined()`] by two Newton-Raphson steps in case of [`prim@f32`] and
+	/// by three in case of [`prim@f64`].
+	///
+	/// The result is within $2$ ULP of the correctly rounded result. Falls back to
+	/// [`Self::recip_sqrt()`] unless `self` is a positive normal number.
+	#[must_use]
+	fn rsqrt_accurate(self) -> Self;
 	/// Returns the cube root of a number.
 	#[must_use]
 	fn cbrt(self) -> Self;
@@ -375,6 +802,17 @@ where
 	/// Returns `(self.sin(), self.cos())`.
 	#[must_use]
 	fn sin_cos(self) -> (Self, Self);
+	/// Computes the point on the unit circle at angle `self` in radians, i.e., $e^{i x}$ of `self`
+	/// as $x$.
+	///
+	/// Returns `(self.cos(), self.sin())` as `(real, imag)` parts, i.e., [`Self::sin_cos()`] in
+	/// reversed order with exactly the same values.
+	#[must_use]
+	#[inline]
+	fn cis(self) -> (Self, Self) {
+		let (sin, cos) = self.sin_cos();
+		(cos, sin)
+	}
 	/// Computes the tangent of a number in radians.
 	#[must_use]
 	fn tan(self) -> Self;
@@ -416,6 +854,157 @@ where
 	/// $$
 	#[must_use]
 	fn atan2(self, other: Self) -> Self;
+	/// Approximates the sine of `self`, see [`SimdReal::sin_fast()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// fn check<R: Real>() {
+	/// 	let tolerance = R::from(10u8).powi(-5);
+	/// 	for i in -10_000..=10_000i16 {
+	/// 		let x = R::from(i) / R::from(100u8);
+	/// 		assert!((x.sin_fast() - x.sin()).abs() < tolerance);
+	/// 	}
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[must_use]
+	#[inline]
+	fn sin_fast(self) -> Self {
+		Self::Simd::<1>::splat(self).sin_fast()[0]
+	}
+	/// Approximates the cosine of `self`, see [`SimdReal::cos_fast()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// fn check<R: Real>() {
+	/// 	let tolerance = R::from(10u8).powi(-5);
+	/// 	for i in -10_000..=10_000i16 {
+	/// 		let x = R::from(i) / R::from(100u8);
+	/// 		assert!((x.cos_fast() - x.cos()).abs() < tolerance);
+	/// 	}
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[must_use]
+	#[inline]
+	fn cos_fast(self) -> Self {
+		Self::Simd::<1>::splat(self).cos_fast()[0]
+	}
+	/// Approximates the four quadrant arctangent of `self` as $y$ and `other` as $x$ in radians,
+	/// see [`SimdReal::atan2_fast()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// fn check<R: Real>() {
+	/// 	let tolerance = R::from(2u8) * R::from(10u8).powi(-6);
+	/// 	for i in -100..=100i8 {
+	/// 		for j in -100..=100i8 {
+	/// 			let (y, x) = (R::from(i) / R::from(50u8), R::from(j) / R::from(50u8));
+	/// 			assert!((y.atan2_fast(x) - y.atan2(x)).abs() < tolerance);
+	/// 		}
+	/// 	}
+	/// 	assert!(R::ONE.atan2_fast(R::NAN).is_nan());
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[must_use]
+	#[inline]
+	fn atan2_fast(self, other: Self) -> Self {
+		Self::Simd::<1>::splat(self).atan2_fast(Self::Simd::splat(other))[0]
+	}
+	/// Approximates the exponential function of `self`, see [`SimdReal::exp_fast()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// fn check<R: Real>() {
+	/// 	let tolerance = R::from(10u8).powi(-5);
+	/// 	for i in -8_000..=8_000i16 {
+	/// 		let x = R::from(i) / R::from(100u8);
+	/// 		let exp = x.exp();
+	/// 		assert!(((x.exp_fast() - exp) / exp).abs() < tolerance);
+	/// 	}
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[must_use]
+	#[inline]
+	fn exp_fast(self) -> Self {
+		Self::Simd::<1>::splat(self).exp_fast()[0]
+	}
+	/// Approximates the natural logarithm of `self`, see [`SimdReal::ln_fast()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::Real;
+	///
+	/// fn check<R: Real>() {
+	/// 	let (relative, absolute) = (
+	/// 		R::from(5u8) * R::from(10u8).powi(-6),
+	/// 		R::from(5u8) * R::from(10u8).powi(-7),
+	/// 	);
+	/// 	for i in -8_000..=8_000i16 {
+	/// 		let x = (R::from(i) / R::from(100u8)).exp();
+	/// 		let (ln, error) = (x.ln(), (x.ln_fast() - x.ln()).abs());
+	/// 		assert!(error < relative * ln.abs() || error < absolute);
+	/// 	}
+	/// 	assert_eq!(R::ZERO.ln_fast(), R::NEG_INFINITY);
+	/// 	assert!((-R::ONE).ln_fast().is_nan());
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[must_use]
+	#[inline]
+	fn ln_fast(self) -> Self {
+		Self::Simd::<1>::splat(self).ln_fast()[0]
+	}
+
+	/// Computes the gamma function $\Gamma(x)$ of `self` as $x$.
+	///
+	/// Returns [`Self::INFINITY`] with the sign of `self` for $x = \pm 0$ and NaN for negative
+	/// integers as the poles of $\Gamma(x)$.
+	#[must_use]
+	fn gamma(self) -> Self;
+	/// Computes the natural logarithm of the absolute value of the gamma function
+	/// $\ln |\Gamma(x)|$ of `self` as $x$.
+	///
+	/// Returns [`Self::INFINITY`] for non-positive integers as the poles of $\Gamma(x)$. Unlike
+	/// `self.gamma().abs().ln()`, it does not overflow for large $x$.
+	#[must_use]
+	fn ln_gamma(self) -> Self;
+	/// Computes the error function $\operatorname{erf}(x)$ of `self` as $x$.
+	///
+	/// Returns exactly $0$ for $x = 0$ with the sign of `self` and $\pm 1$ for $x = \pm\infty$.
+	#[must_use]
+	fn erf(self) -> Self;
+	/// Computes the complementary error function $\operatorname{erfc}(x) = 1 - \operatorname{erf}(x)$
+	/// of `self` as $x$.
+	///
+	/// Unlike `1 - self.erf()`, it is accurate in the tail of large $x$.
+	#[must_use]
+	fn erfc(self) -> Self;
 
 	/// Returns an ordering between self and other values.
 	///
@@ -441,6 +1030,136 @@ where
 	/// zero as equal, while [`Self::total_cmp()`] does not.
 	#[must_use]
 	fn total_cmp(&self, other: &Self) -> Ordering;
+	/// Returns the signed number of [ULP]s from `other` to `self`.
+	///
+	/// The result is positive if `self` is greater than `other` in accordance to
+	/// [`Self::total_cmp()`], negative if less, and zero if both are bitwise equal. Numbers of
+	/// different signs are counted across zero where `-0.0` and `0.0` are one ULP apart.
+	///
+	/// Saturates to [`i64::MAX`] or [`i64::MIN`] if either number is NaN, unless both are bitwise
+	/// equal NaNs.
+	///
+	/// [ULP]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+	#[must_use]
+	fn signed_ulps(self, other: Self) -> i64;
+	/// Returns the machine epsilon at the magnitude of `self`, i.e., the size of its [ULP].
+	///
+	/// Equals `next_up(self) - self` for positive finite `self` which is [`Self::EPSILON`] times
+	/// two to the power of its exponent. Computes it by masking the exponent bits. Returns the
+	/// smallest subnormal number for zero and subnormals, infinity for infinities, and NaN for
+	/// NaN.
+	///
+	/// [ULP]: https://en.wikipedia.org/wiki/Unit_in_the_last_place
+	#[must_use]
+	#[inline]
+	fn epsilon_at(self) -> Self {
+		if self.is_nan() {
+			return self;
+		}
+		let exponent = Self::from_bits(self.to_bits() & Self::INFINITY.to_bits());
+		(exponent * Self::EPSILON).max(Self::from_bits(Self::Bits::ONE))
+	}
+
+	/// Approximates the derivative of `f` at `x` by the central difference with step size `h`.
+	///
+	/// $$
+	/// f'(x) \approx {f(x + h) - f(x - h) \over 2h}
+	/// $$
+	#[must_use]
+	#[inline]
+	fn central_diff(f: impl Fn(Self) -> Self, x: Self, h: Self) -> Self {
+		(f(x + h) - f(x - h)) / (Self::TWO * h)
+	}
+	/// Approximates the derivative of `f` at `x` by the central difference with automatic step
+	/// size.
+	///
+	/// The step size $h = \sqrt\[3]{\epsilon} \max(|x|, 1)$ balances the truncation error against
+	/// the rounding error. It is adjusted to be exactly representable in the sum $x + h$.
+	#[must_use]
+	#[inline]
+	fn central_diff_auto(f: impl Fn(Self) -> Self, x: Self) -> Self {
+		let h = Self::CBRT_EPSILON * x.abs().max(Self::ONE);
+		Self::central_diff(f, x, (x + h) - x)
+	}
+	/// Computes the Newton-Raphson step $x - {f \over f'}$ from `x` given the function value `f`
+	/// and its derivative `df` at `x`.
+	///
+	/// Returns `x` unchanged if `df` is approximately zero in the sense that
+	/// $|f'| \le \epsilon |f|$, i.e., if the step would exceed $1 \over \epsilon$ in magnitude or
+	/// both `f` and `df` are zero, preventing divergence.
+	#[must_use]
+	#[inline]
+	fn newton_step(x: Self, f: Self, df: Self) -> Self {
+		if df.abs() <= Self::EPSILON * f.abs() {
+			x
+		} else {
+			x - f / df
+		}
+	}
+	/// Finds a root of `f` with derivative `df` by Newton-Raphson steps starting at `x0`.
+	///
+	/// Iterates [`Self::newton_step()`] until a step is not greater than `tol` in magnitude or
+	/// after at most `max_iter` steps. Returns the last iterate, which is not a root if `f` has
+	/// not converged, e.g., due to an approximately zero derivative.
+	#[must_use]
+	#[inline]
+	fn newton_solve(
+		f: impl Fn(Self) -> Self,
+		df: impl Fn(Self) -> Self,
+		x0: Self,
+		tol: Self,
+		max_iter: usize,
+	) -> Self {
+		let mut x = x0;
+		for _ in 0..max_iter {
+			let next = Self::newton_step(x, f(x), df(x));
+			let step = (next - x).abs();
+			x = next;
+			if step <= tol {
+				break;
+			}
+		}
+		x
+	}
+
+	/// Returns the factorial $n!$ computed in floating-point.
+	///
+	/// Returns [`Self::INFINITY`] on overflow, that is for $n > 34$ in case of [`prim@f32`] and
+	/// for $n > 170$ in case of [`prim@f64`].
+	#[must_use]
+	fn factorial(n: u32) -> Self;
+	/// Returns the binomial coefficient $\binom{n}{k}$ computed in floating-point.
+	///
+	/// Returns [`Self::ZERO`] for $k > n$ and [`Self::INFINITY`] on overflow, that is for
+	/// $\binom{n}{\lfloor n/2 \rfloor}$ with $n > 131$ in case of [`prim@f32`] and with $n > 1029$
+	/// in case of [`prim@f64`].
+	#[must_use]
+	fn binomial(n: u32, k: u32) -> Self;
+	/// Fits a line to the paired samples `xs` and `ys` by ordinary least squares returning its
+	/// `(slope, intercept)`.
+	///
+	/// Sums the deviations from the means in a second pass for numerical stability. Returns a NaN
+	/// slope and intercept if all `xs` are equal or if the slices are empty.
+	///
+	/// # Panics
+	///
+	/// Panics if `xs` and `ys` differ in length.
+	#[must_use]
+	#[inline]
+	fn slope_intercept(xs: &[Self], ys: &[Self]) -> (Self, Self) {
+		assert_eq!(xs.len(), ys.len(), "slices differ in length");
+		let n = xs.iter().map(|_| Self::ONE).sum::<Self>();
+		let mean_x = xs.iter().sum::<Self>() / n;
+		let mean_y = ys.iter().sum::<Self>() / n;
+		let (mut sxx, mut sxy) = (Self::ZERO, Self::ZERO);
+		for (&x, &y) in xs.iter().zip(ys) {
+			let dx = x - mean_x;
+			sxx = dx.mul_add(dx, sxx);
+			sxy = dx.mul_add(y - mean_y, sxy);
+		}
+		let slope = sxy / sxx;
+		(slope, (-slope).mul_add(mean_x, mean_y))
+	}
 
 	/// Constructs a SIMD vector by setting all lanes to the given value.
 	#[must_use]
@@ -506,6 +1225,77 @@ where
 	{
 		Self::Simd::as_simd_mut(slice)
 	}
+
+	/// Iterates over `slice` in chunks of SIMD vectors yielding each vector together with its count
+	/// of valid lanes.
+	///
+	/// The count is `N` for all but the last vector, whose missing lanes are padded with `pad`
+	/// unless `slice.len()` is a multiple of `N`. Yields nothing for an empty `slice`.
+	#[inline]
+	fn simd_iter<const N: usize>(
+		slice: &[Self],
+		pad: Self,
+	) -> impl Iterator<Item = (Self::Simd<N>, usize)>
+	where
+		LaneCount<N>: SupportedLaneCount,
+	{
+		slice
+			.chunks(N)
+			.map(move |chunk| (Self::Simd::from_slice_padded(chunk, pad), chunk.len()))
+	}
+	/// Evaluates the polynomial of `coeffs` at each number of `xs` into `out` using SIMD vectors
+	/// of `N` lanes.
+	///
+	/// Coefficients are ordered by ascending degree, see [`SimdReal::simd_poly()`]. Loads `xs` in
+	/// chunks of `N` numbers padding the remainder and stores the results truncating it.
+	///
+	/// # Panics
+	///
+	/// Panics if `xs` and `out` differ in length.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// use lav::{ApproxEq, Bits, Real};
+	///
+	/// fn horner<R: Real>(x: R, coeffs: &[R]) -> R {
+	/// 	coeffs.iter().rev().fold(R::ZERO, |y, &c| y.mul_add(x, c))
+	/// }
+	///
+	/// fn check<R: Real>() {
+	/// 	let xs = (0..13u8)
+	/// 		.map(|i| R::from(i) / R::from(7u8) - R::ONE)
+	/// 		.collect::<Vec<R>>();
+	/// 	let coeffs = [
+	/// 		R::from(3u8),
+	/// 		-R::from(2u8),
+	/// 		R::from(5u8) / R::from(4u8),
+	/// 		R::from(9u8).recip(),
+	/// 	];
+	/// 	let mut out = vec![R::NAN; xs.len()];
+	/// 	R::poly_eval_slice::<4>(&xs, &coeffs, &mut out);
+	/// 	for (&x, y) in xs.iter().zip(&out) {
+	/// 		assert!(horner(x, &coeffs).approx_eq(y, R::ZERO, R::Bits::ONE));
+	/// 	}
+	/// 	R::poly_eval_slice::<8>(&xs, &[], &mut out);
+	/// 	assert!(out.iter().all(|&y| y == R::ZERO));
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[inline]
+	fn poly_eval_slice<const N: usize>(xs: &[Self], coeffs: &[Self], out: &mut [Self])
+	where
+		LaneCount<N>: SupportedLaneCount,
+	{
+		assert_eq!(xs.len(), out.len(), "slices differ in length");
+		for (xs, out) in xs.chunks(N).zip(out.chunks_mut(N)) {
+			Self::Simd::<N>::from_slice_padded(xs, Self::ZERO)
+				.simd_poly(coeffs)
+				.store_truncated(out);
+		}
+	}
 }
 
 impl<R: Real> ApproxEq<R> for R {
@@ -513,7 +1303,14 @@ impl<R: Real> ApproxEq<R> for R {
 		Real::abs(*self - other) <= epsilon
 			|| !self.is_nan()
 				&& !other.is_nan()
-				&& self.is_sign_negative() == self.is_sign_negative()
+				&& self.is_sign_negative() == other.is_sign_negative()
 				&& self.to_bits().abs_sub(other.to_bits()) <= ulp
 	}
 }
+
+impl<R: Real> ApproxEqRel<R> for R {
+	fn approx_eq_rel(&self, other: &R, rel: R) -> bool {
+		let diff = Real::abs(*self - *other);
+		self == other || diff.is_finite() && diff <= rel * Real::abs(*self).max(Real::abs(*other))
+	}
+}
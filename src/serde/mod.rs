@@ -0,0 +1,188 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! Serialization of SIMD vectors as arrays of their lanes.
+//!
+//! Provides the free functions [`serialize_simd()`] and [`deserialize_simd()`] for any vector
+//! convertible from and to an array of `N` lanes, irrespective of whether the nightly `serde`
+//! support of [`core::simd`] is enabled. The modules [`simd_real`] and [`simd_bits`] wrap them
+//! for [`SimdReal`] and [`SimdBits`] vectors as fields annotated with
+//! `#[serde(with = "lav::serde::simd_real")]` and `#[serde(with = "lav::serde::simd_bits")]`.
+//!
+//! # Examples
+//!
+//! ```
+//! #![feature(portable_simd)]
+//!
+//! use core::simd::Simd;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Debug, PartialEq, Serialize, Deserialize)]
+//! struct Lanes {
+//! 	#[serde(with = "lav::serde::simd_real")]
+//! 	real: Simd<f32, 4>,
+//! 	#[serde(with = "lav::serde::simd_bits")]
+//! 	bits: Simd<u64, 8>,
+//! }
+//!
+//! let lanes = Lanes {
+//! 	real: Simd::from_array([1.5, -0.0, f32::MAX, f32::MIN_POSITIVE]),
+//! 	bits: Simd::from_array([0, 1, 2, 3, 5, 8, 13, u64::MAX]),
+//! };
+//! let json = serde_json::to_string(&lanes).unwrap();
+//! assert_eq!(serde_json::from_str::<Lanes>(&json).unwrap(), lanes);
+//! assert!(serde_json::from_str::<Lanes>(r#"{"real":[1.0,2.0,3.0],"bits":[]}"#).is_err());
+//! ```
+
+use super::{Bits, Real, SimdBits, SimdReal};
+use ::serde::{
+	de::{self, SeqAccess, Visitor},
+	ser::SerializeTuple,
+	Deserialize, Deserializer, Serialize, Serializer,
+};
+use core::{
+	fmt,
+	marker::PhantomData,
+	simd::{LaneCount, SupportedLaneCount},
+};
+
+/// Serializes `vector` as a tuple of its `N` lanes like the array `[T; N]`.
+///
+/// Unlike arrays, supports any number of lanes instead of at most 32.
+///
+/// # Errors
+///
+/// Forwards the errors of `serializer`.
+pub fn serialize_simd<T, V, S, const N: usize>(vector: &V, serializer: S) -> Result<S::Ok, S::Error>
+where
+	T: Serialize,
+	V: AsRef<[T; N]>,
+	S: Serializer,
+{
+	let mut tuple = serializer.serialize_tuple(N)?;
+	for lane in vector.as_ref() {
+		tuple.serialize_element(lane)?;
+	}
+	tuple.end()
+}
+
+/// Deserializes a vector from a tuple of its `N` lanes like the array `[T; N]`.
+///
+/// Unlike arrays, supports any number of lanes instead of at most 32.
+///
+/// # Errors
+///
+/// Fails if there are less than `N` lanes and forwards the errors of `deserializer`.
+pub fn deserialize_simd<'de, T, V, D, const N: usize>(deserializer: D) -> Result<V, D::Error>
+where
+	T: Deserialize<'de> + Copy + Default,
+	V: From<[T; N]>,
+	D: Deserializer<'de>,
+{
+	deserializer
+		.deserialize_tuple(N, LanesVisitor(PhantomData))
+		.map(V::from)
+}
+
+/// Visits a sequence of `N` lanes.
+struct LanesVisitor<T, const N: usize>(PhantomData<T>);
+
+impl<'de, T, const N: usize> Visitor<'de> for LanesVisitor<T, N>
+where
+	T: Deserialize<'de> + Copy + Default,
+{
+	type Value = [T; N];
+
+	fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+		write!(formatter, "an array of {N} lanes")
+	}
+
+	fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+		let mut lanes = [T::default(); N];
+		for (idx, lane) in lanes.iter_mut().enumerate() {
+			*lane = seq
+				.next_element()?
+				.ok_or_else(|| de::Error::invalid_length(idx, &self))?;
+		}
+		Ok(lanes)
+	}
+}
+
+/// Serialization of [`SimdReal`] vectors as arrays `[R; N]`.
+pub mod simd_real {
+	use super::{
+		deserialize_simd, serialize_simd, Deserialize, Deserializer, LaneCount, Real, Serialize,
+		Serializer, SimdReal, SupportedLaneCount,
+	};
+
+	/// Serializes `vector` as array `[R; N]`, see [`serialize_simd()`].
+	///
+	/// # Errors
+	///
+	/// Forwards the errors of `serializer`.
+	pub fn serialize<R, V, S, const N: usize>(vector: &V, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		LaneCount<N>: SupportedLaneCount,
+		R: Real + Serialize,
+		V: SimdReal<R, N>,
+		S: Serializer,
+	{
+		serialize_simd(vector, serializer)
+	}
+
+	/// Deserializes a vector from an array `[R; N]`, see [`deserialize_simd()`].
+	///
+	/// # Errors
+	///
+	/// Fails if there are less than `N` lanes and forwards the errors of `deserializer`.
+	pub fn deserialize<'de, R, V, D, const N: usize>(deserializer: D) -> Result<V, D::Error>
+	where
+		LaneCount<N>: SupportedLaneCount,
+		R: Real + Deserialize<'de>,
+		V: SimdReal<R, N>,
+		D: Deserializer<'de>,
+	{
+		deserialize_simd(deserializer)
+	}
+}
+
+/// Serialization of [`SimdBits`] vectors as arrays `[B; N]`.
+pub mod simd_bits {
+	use super::{
+		deserialize_simd, serialize_simd, Bits, Deserialize, Deserializer, LaneCount, Serialize,
+		Serializer, SimdBits, SupportedLaneCount,
+	};
+
+	/// Serializes `vector` as array `[B; N]`, see [`serialize_simd()`].
+	///
+	/// # Errors
+	///
+	/// Forwards the errors of `serializer`.
+	pub fn serialize<B, V, S, const N: usize>(vector: &V, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		LaneCount<N>: SupportedLaneCount,
+		B: Bits + Serialize,
+		V: SimdBits<B, N>,
+		S: Serializer,
+	{
+		serialize_simd(vector, serializer)
+	}
+
+	/// Deserializes a vector from an array `[B; N]`, see [`deserialize_simd()`].
+	///
+	/// # Errors
+	///
+	/// Fails if there are less than `N` lanes and forwards the errors of `deserializer`.
+	pub fn deserialize<'de, B, V, D, const N: usize>(deserializer: D) -> Result<V, D::Error>
+	where
+		LaneCount<N>: SupportedLaneCount,
+		B: Bits + Deserialize<'de>,
+		V: SimdBits<B, N>,
+		D: Deserializer<'de>,
+	{
+		deserialize_simd(deserializer)
+	}
+}
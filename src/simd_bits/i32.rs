@@ -0,0 +1,125 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{Select, SimdBits};
+use core::simd::{
+	cmp::{SimdPartialEq, SimdPartialOrd},
+	num::SimdInt,
+	LaneCount, Mask, Simd, SupportedLaneCount,
+};
+
+impl<const N: usize> SimdBits<i32, N> for Simd<i32, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Mask = Mask<i32, N>;
+
+	#[inline]
+	fn splat(value: i32) -> Self {
+		Self::splat(value)
+	}
+
+	#[inline]
+	fn as_simd(slice: &[i32]) -> (&[i32], &[Self], &[i32]) {
+		slice.as_simd()
+	}
+
+	#[inline]
+	fn as_simd_mut(slice: &mut [i32]) -> (&mut [i32], &mut [Self], &mut [i32]) {
+		slice.as_simd_mut()
+	}
+
+	#[inline]
+	fn gather_or(slice: &[i32], idxs: Simd<usize, N>, or: Self) -> Self {
+		Self::gather_or(slice, idxs, or)
+	}
+	#[inline]
+	fn gather_or_default(slice: &[i32], idxs: Simd<usize, N>) -> Self {
+		Self::gather_or_default(slice, idxs)
+	}
+	#[inline]
+	fn gather_select(
+		slice: &[i32],
+		enable: Mask<isize, N>,
+		idxs: Simd<usize, N>,
+		or: Self,
+	) -> Self {
+		Self::gather_select(slice, enable, idxs, or)
+	}
+
+	#[inline]
+	fn scatter(self, slice: &mut [i32], idxs: Simd<usize, N>) {
+		self.scatter(slice, idxs);
+	}
+	#[inline]
+	fn scatter_select(self, slice: &mut [i32], enable: Mask<isize, N>, idxs: Simd<usize, N>) {
+		self.scatter_select(slice, enable, idxs);
+	}
+
+	#[inline]
+	fn simd_eq(self, other: Self) -> Self::Mask {
+		SimdPartialEq::simd_eq(self, other)
+	}
+	#[inline]
+	fn simd_ne(self, other: Self) -> Self::Mask {
+		SimdPartialEq::simd_ne(self, other)
+	}
+	#[inline]
+	fn simd_lt(self, other: Self) -> Self::Mask {
+		SimdPartialOrd::simd_lt(self, other)
+	}
+	#[inline]
+	fn simd_gt(self, other: Self) -> Self::Mask {
+		SimdPartialOrd::simd_gt(self, other)
+	}
+	#[inline]
+	fn simd_le(self, other: Self) -> Self::Mask {
+		SimdPartialOrd::simd_le(self, other)
+	}
+	#[inline]
+	fn simd_ge(self, other: Self) -> Self::Mask {
+		SimdPartialOrd::simd_ge(self, other)
+	}
+
+	#[inline]
+	fn saturating_add(self, other: Self) -> Self {
+		SimdInt::saturating_add(self, other)
+	}
+	#[inline]
+	fn saturating_sub(self, other: Self) -> Self {
+		SimdInt::saturating_sub(self, other)
+	}
+
+	#[inline]
+	fn reduce_and(self) -> i32 {
+		SimdInt::reduce_and(self)
+	}
+	#[inline]
+	fn reduce_or(self) -> i32 {
+		SimdInt::reduce_or(self)
+	}
+	#[inline]
+	fn reduce_xor(self) -> i32 {
+		SimdInt::reduce_xor(self)
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	#[inline]
+	fn lookup(table: Self, idxs: Self) -> Self {
+		let enable = SimdPartialOrd::simd_lt(idxs.cast::<u32>(), Simd::splat(N as u32));
+		Self::gather_select(table.as_array(), enable.cast(), idxs.cast(), Self::splat(0))
+	}
+}
+
+impl<const N: usize> Select<Mask<i32, N>> for Simd<i32, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn select(mask: Mask<i32, N>, true_values: Self, false_values: Self) -> Self {
+		mask.select(true_values, false_values)
+	}
+}
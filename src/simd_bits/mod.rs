@@ -13,9 +13,11 @@ use core::{
 	ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
 	ops::{Index, IndexMut},
 	ops::{Shl, ShlAssign, Shr, ShrAssign},
-	simd::{LaneCount, Simd, SupportedLaneCount},
+	simd::{LaneCount, Mask, Simd, SupportedLaneCount},
 };
 
+mod i32;
+mod i64;
 mod u32;
 mod u64;
 
@@ -117,6 +119,64 @@ where
 	#[must_use]
 	fn as_simd_mut(slice: &mut [B]) -> (&mut [B], &mut [Self], &mut [B]);
 
+	/// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector.
+	///
+	/// If an index is out-of-bounds, the lane is instead selected from the `or` vector.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdBits;
+	///
+	/// let slice = [10u32, 11, 12, 13, 14];
+	/// let idxs = Simd::from_array([4, 0, 5, 2]);
+	/// let x: Simd<u32, 4> = SimdBits::gather_or(&slice, idxs, Simd::splat(99));
+	/// assert_eq!(x.to_array(), [14, 10, 99, 12]);
+	/// ```
+	#[must_use]
+	fn gather_or(slice: &[B], idxs: Simd<usize, N>, or: Self) -> Self;
+	/// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector.
+	///
+	/// If an index is out-of-bounds, the lane is set to the default value for the type.
+	#[must_use]
+	fn gather_or_default(slice: &[B], idxs: Simd<usize, N>) -> Self;
+	/// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector.
+	///
+	/// The mask `enable`s all `true` lanes and disables all `false` lanes.
+	/// If an index is disabled or is out-of-bounds, the lane is selected from the `or` vector.
+	#[must_use]
+	fn gather_select(slice: &[B], enable: Mask<isize, N>, idxs: Simd<usize, N>, or: Self) -> Self;
+
+	/// Writes the values in a SIMD vector to potentially discontiguous indices in `slice`.
+	///
+	/// If two lanes in the scattered vector would write to the same index only the last lane is
+	/// guaranteed to actually be written.
+	fn scatter(self, slice: &mut [B], idxs: Simd<usize, N>);
+	/// Writes the values in a SIMD vector to multiple potentially discontiguous indices in `slice`.
+	///
+	/// The mask `enable`s all `true` lanes and disables all `false` lanes. If an enabled index is
+	/// out-of-bounds, the lane is not written. If two enabled lanes in the scattered vector would
+	/// write to the same index, only the last lane is guaranteed to actually be written.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::{Mask, Simd};
+	/// use lav::SimdBits;
+	///
+	/// let mut slice = [0u64; 5];
+	/// let x = Simd::<u64, 4>::from_array([1, 2, 3, 4]);
+	/// let enable = Mask::from_array([true, false, true, true]);
+	/// SimdBits::scatter_select(x, &mut slice, enable, Simd::from_array([4, 0, 1, 7]));
+	/// assert_eq!(slice, [0, 3, 0, 0, 1]);
+	/// ```
+	fn scatter_select(self, slice: &mut [B], enable: Mask<isize, N>, idxs: Simd<usize, N>);
+
 	/// Test if each lane is equal to the corresponding lane in `other`.
 	#[must_use]
 	fn simd_eq(self, other: Self) -> Self::Mask;
@@ -145,10 +205,122 @@ where
 
 	/// Lanewise absolute subtract.
 	///
-	/// Equals `self.saturating_sub(other) | other.saturating_sub(self)`.
+	/// Subtracts the lesser from the greater lane. Saturates at `B::MAX` for signed integers if the
+	/// difference overflows, e.g., if either lane is `B::MIN` and the other is not negative.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdBits;
+	///
+	/// let x = Simd::<i32, 4>::from_array([i32::MIN, i32::MAX, -1, i32::MIN]);
+	/// let y = Simd::from_array([i32::MAX, i32::MIN, 1, i32::MIN]);
+	/// assert_eq!(
+	/// 	SimdBits::abs_sub(x, y).to_array(),
+	/// 	[i32::MAX, i32::MAX, 2, 0]
+	/// );
+	/// assert_eq!(
+	/// 	SimdBits::saturating_sub(x, y).to_array(),
+	/// 	[i32::MIN, i32::MAX, -2, 0]
+	/// );
+	/// assert_eq!(
+	/// 	SimdBits::saturating_add(x, x).to_array(),
+	/// 	[i32::MIN, i32::MAX, -2, i32::MIN]
+	/// );
+	/// let x = Simd::<i64, 2>::from_array([i64::MIN, -1]);
+	/// let y = Simd::from_array([0, i64::MAX]);
+	/// assert_eq!(SimdBits::abs_sub(x, y).to_array(), [i64::MAX, i64::MAX]);
+	/// assert_eq!(
+	/// 	SimdBits::saturating_add(x, y).to_array(),
+	/// 	[i64::MIN, i64::MAX - 1]
+	/// );
+	/// ```
 	#[must_use]
 	#[inline]
 	fn abs_sub(self, other: Self) -> Self {
-		self.saturating_sub(other) | other.saturating_sub(self)
+		let gt = self.simd_gt(other);
+		gt.select(self, other)
+			.saturating_sub(gt.select(other, self))
 	}
+
+	/// Horizontal bitwise AND of all lanes.
+	///
+	/// There is always at least one lane to reduce as `N` is at least one.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdBits;
+	///
+	/// assert_eq!(
+	/// 	Simd::<u32, 2>::from_array([0b1100, 0b1010]).reduce_and(),
+	/// 	0b1000
+	/// );
+	/// assert_eq!(
+	/// 	Simd::<u64, 2>::from_array([0b1100, 0b1010]).reduce_and(),
+	/// 	0b1000
+	/// );
+	/// ```
+	#[must_use]
+	fn reduce_and(self) -> B;
+	/// Horizontal bitwise OR of all lanes.
+	///
+	/// There is always at least one lane to reduce as `N` is at least one.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdBits;
+	///
+	/// assert_eq!(
+	/// 	Simd::<u32, 2>::from_array([0b1100, 0b1010]).reduce_or(),
+	/// 	0b1110
+	/// );
+	/// assert_eq!(
+	/// 	Simd::<u64, 2>::from_array([0b1100, 0b1010]).reduce_or(),
+	/// 	0b1110
+	/// );
+	/// ```
+	#[must_use]
+	fn reduce_or(self) -> B;
+	/// Horizontal bitwise XOR of all lanes.
+	///
+	/// There is always at least one lane to reduce as `N` is at least one.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdBits;
+	///
+	/// assert_eq!(
+	/// 	Simd::<u32, 2>::from_array([0b1100, 0b1010]).reduce_xor(),
+	/// 	0b0110
+	/// );
+	/// assert_eq!(
+	/// 	Simd::<u64, 2>::from_array([0b1100, 0b1010]).reduce_xor(),
+	/// 	0b0110
+	/// );
+	/// ```
+	#[must_use]
+	fn reduce_xor(self) -> B;
+
+	/// Looks up the lanes of `table` indexed by the lanes of `idxs`.
+	///
+	/// Each lane of the result is the lane of `table` selected by the corresponding lane of `idxs`
+	/// like a byte shuffle restricted to lane granularity. Lanes with out-of-range indices, i.e.,
+	/// not less than `N`, are set to zero.
+	#[must_use]
+	fn lookup(table: Self, idxs: Self) -> Self;
 }
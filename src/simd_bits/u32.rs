@@ -32,6 +32,33 @@ where
 		slice.as_simd_mut()
 	}
 
+	#[inline]
+	fn gather_or(slice: &[u32], idxs: Simd<usize, N>, or: Self) -> Self {
+		Self::gather_or(slice, idxs, or)
+	}
+	#[inline]
+	fn gather_or_default(slice: &[u32], idxs: Simd<usize, N>) -> Self {
+		Self::gather_or_default(slice, idxs)
+	}
+	#[inline]
+	fn gather_select(
+		slice: &[u32],
+		enable: Mask<isize, N>,
+		idxs: Simd<usize, N>,
+		or: Self,
+	) -> Self {
+		Self::gather_select(slice, enable, idxs, or)
+	}
+
+	#[inline]
+	fn scatter(self, slice: &mut [u32], idxs: Simd<usize, N>) {
+		self.scatter(slice, idxs);
+	}
+	#[inline]
+	fn scatter_select(self, slice: &mut [u32], enable: Mask<isize, N>, idxs: Simd<usize, N>) {
+		self.scatter_select(slice, enable, idxs);
+	}
+
 	#[inline]
 	fn simd_eq(self, other: Self) -> Self::Mask {
 		SimdPartialEq::simd_eq(self, other)
@@ -65,6 +92,26 @@ where
 	fn saturating_sub(self, other: Self) -> Self {
 		SimdUint::saturating_sub(self, other)
 	}
+
+	#[inline]
+	fn reduce_and(self) -> u32 {
+		SimdUint::reduce_and(self)
+	}
+	#[inline]
+	fn reduce_or(self) -> u32 {
+		SimdUint::reduce_or(self)
+	}
+	#[inline]
+	fn reduce_xor(self) -> u32 {
+		SimdUint::reduce_xor(self)
+	}
+
+	#[allow(clippy::cast_possible_truncation)]
+	#[inline]
+	fn lookup(table: Self, idxs: Self) -> Self {
+		let enable = SimdPartialOrd::simd_lt(idxs, Self::splat(N as u32));
+		Self::gather_select(table.as_array(), enable.cast(), idxs.cast(), Self::splat(0))
+	}
 }
 
 impl<const N: usize> Select<Mask<i32, N>> for Simd<u32, N>
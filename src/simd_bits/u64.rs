@@ -32,6 +32,33 @@ where
 		slice.as_simd_mut()
 	}
 
+	#[inline]
+	fn gather_or(slice: &[u64], idxs: Simd<usize, N>, or: Self) -> Self {
+		Self::gather_or(slice, idxs, or)
+	}
+	#[inline]
+	fn gather_or_default(slice: &[u64], idxs: Simd<usize, N>) -> Self {
+		Self::gather_or_default(slice, idxs)
+	}
+	#[inline]
+	fn gather_select(
+		slice: &[u64],
+		enable: Mask<isize, N>,
+		idxs: Simd<usize, N>,
+		or: Self,
+	) -> Self {
+		Self::gather_select(slice, enable, idxs, or)
+	}
+
+	#[inline]
+	fn scatter(self, slice: &mut [u64], idxs: Simd<usize, N>) {
+		self.scatter(slice, idxs);
+	}
+	#[inline]
+	fn scatter_select(self, slice: &mut [u64], enable: Mask<isize, N>, idxs: Simd<usize, N>) {
+		self.scatter_select(slice, enable, idxs);
+	}
+
 	#[inline]
 	fn simd_eq(self, other: Self) -> Self::Mask {
 		SimdPartialEq::simd_eq(self, other)
@@ -65,6 +92,25 @@ where
 	fn saturating_sub(self, other: Self) -> Self {
 		SimdUint::saturating_sub(self, other)
 	}
+
+	#[inline]
+	fn reduce_and(self) -> u64 {
+		SimdUint::reduce_and(self)
+	}
+	#[inline]
+	fn reduce_or(self) -> u64 {
+		SimdUint::reduce_or(self)
+	}
+	#[inline]
+	fn reduce_xor(self) -> u64 {
+		SimdUint::reduce_xor(self)
+	}
+
+	#[inline]
+	fn lookup(table: Self, idxs: Self) -> Self {
+		let enable = SimdPartialOrd::simd_lt(idxs, Self::splat(N as u64));
+		Self::gather_select(table.as_array(), enable.cast(), idxs.cast(), Self::splat(0))
+	}
 }
 
 impl<const N: usize> Select<Mask<i64, N>> for Simd<u64, N>
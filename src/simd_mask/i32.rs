@@ -24,6 +24,10 @@ where
 	fn to_array(self) -> [bool; N] {
 		self.to_array()
 	}
+	#[inline]
+	fn to_bitmask(self) -> u64 {
+		self.to_bitmask()
+	}
 
 	#[inline]
 	fn all(self) -> bool {
@@ -34,6 +38,20 @@ where
 		self.any()
 	}
 
+	#[inline]
+	fn count(self) -> usize {
+		self.to_bitmask().count_ones() as usize
+	}
+	#[inline]
+	fn first_set(self) -> Option<usize> {
+		let bitmask = self.to_bitmask();
+		(bitmask != 0).then(|| bitmask.trailing_zeros() as usize)
+	}
+	#[inline]
+	fn last_set(self) -> Option<usize> {
+		self.to_bitmask().checked_ilog2().map(|lane| lane as usize)
+	}
+
 	#[inline]
 	fn set(&mut self, lane: usize, value: bool) {
 		self.set(lane, value);
@@ -52,6 +52,22 @@ where
 	/// Converts a SIMD vector mask to an array.
 	#[must_use]
 	fn to_array(self) -> [bool; N];
+	/// Converts a SIMD vector mask to a bitmask where bit `lane` is set if `lane` is set.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Mask;
+	/// use lav::SimdMask;
+	///
+	/// let mask = Mask::<i32, 4>::from_array([true, false, true, true]);
+	/// assert_eq!(SimdMask::to_bitmask(mask), 0b1101);
+	/// assert_eq!(SimdMask::to_bitmask(Mask::<i64, 64>::splat(true)), u64::MAX);
+	/// ```
+	#[must_use]
+	fn to_bitmask(self) -> u64;
 
 	/// Constructs a mask with `lane` set to `value` and all the other lanes set to `!value`.
 	#[must_use]
@@ -69,6 +85,61 @@ where
 	#[must_use]
 	fn any(self) -> bool;
 
+	/// Returns the number of set lanes in `0..=N`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Mask;
+	/// use lav::SimdMask;
+	///
+	/// assert_eq!(
+	/// 	Mask::<i32, 4>::from_array([true, false, true, false]).count(),
+	/// 	2
+	/// );
+	/// assert_eq!(Mask::<i32, 16>::splat(true).count(), 16);
+	/// assert_eq!(Mask::<i64, 2>::splat(false).count(), 0);
+	/// assert_eq!(Mask::<i64, 64>::splat(true).count(), 64);
+	/// ```
+	#[must_use]
+	fn count(self) -> usize;
+	/// Returns the index of the first set lane or `None` if no lane is set.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Mask;
+	/// use lav::SimdMask;
+	///
+	/// assert_eq!(SimdMask::first_set(Mask::<i32, 4>::splat(false)), None);
+	/// assert_eq!(SimdMask::first_set(Mask::<i64, 8>::flag(2, true)), Some(2));
+	/// let mask = Mask::<i32, 8>::from_array([false, true, false, false, false, true, false, false]);
+	/// assert_eq!(SimdMask::first_set(mask), Some(1));
+	/// ```
+	#[must_use]
+	fn first_set(self) -> Option<usize>;
+	/// Returns the index of the last set lane or `None` if no lane is set.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Mask;
+	/// use lav::SimdMask;
+	///
+	/// assert_eq!(SimdMask::last_set(Mask::<i64, 4>::splat(false)), None);
+	/// assert_eq!(SimdMask::last_set(Mask::<i32, 8>::flag(2, true)), Some(2));
+	/// let mask = Mask::<i64, 8>::from_array([false, true, false, false, false, true, false, false]);
+	/// assert_eq!(SimdMask::last_set(mask), Some(5));
+	/// ```
+	#[must_use]
+	fn last_set(self) -> Option<usize>;
+
 	/// Sets the value of the specified lane.
 	///
 	/// # Panics
@@ -92,6 +163,16 @@ where
 	fn select<S: Select<Self>>(self, true_values: S, false_values: S) -> S {
 		Select::select(self, true_values, false_values)
 	}
+	/// Chooses lanes from two masks.
+	///
+	/// For each lane in the mask, choose the corresponding lane from `if_true` if that lane mask
+	/// is true, and `if_false` if that lane mask is false. This is [`Self::select()`] for masks,
+	/// e.g., to compose nested conditionals.
+	#[must_use]
+	#[inline]
+	fn select_mask(self, if_true: Self, if_false: Self) -> Self {
+		Select::select(self, if_true, if_false)
+	}
 	/// Negates lanes if their lane mask is true.
 	#[must_use]
 	#[inline]
@@ -4,15 +4,15 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::{ApproxEq, Select, SimdReal};
+use super::{atan2_reduced, ApproxEq, ApproxEqRel, Real, Select, SimdReal};
 use core::simd::{
-	cmp::{SimdPartialEq, SimdPartialOrd},
-	num::SimdFloat,
+	cmp::{SimdOrd, SimdPartialEq, SimdPartialOrd},
+	num::{SimdFloat, SimdUint},
 	LaneCount, Mask, Simd, SupportedLaneCount, Swizzle,
 };
 
-#[cfg(feature = "libm")]
-use super::Real;
+#[cfg(not(feature = "libm"))]
+use super::sin_cos_reduced;
 #[cfg(not(feature = "libm"))]
 use std::simd::StdFloat;
 
@@ -54,11 +54,35 @@ where
 	fn to_array(self) -> [f32; N] {
 		Self::to_array(self)
 	}
+	#[inline]
+	fn cast_f32(self) -> Self {
+		self
+	}
+	#[inline]
+	fn cast_f64(self) -> Simd<f64, N> {
+		SimdFloat::cast(self)
+	}
+	#[inline]
+	fn to_usize_trunc(self) -> Simd<usize, N> {
+		SimdFloat::cast(self)
+	}
+	#[inline]
+	fn to_i32_trunc(self) -> Simd<i32, N> {
+		SimdFloat::cast(self)
+	}
+	#[inline]
+	fn to_i64_trunc(self) -> Simd<i64, N> {
+		SimdFloat::cast(self)
+	}
 
 	#[inline]
 	fn from_slice(slice: &[f32]) -> Self {
 		Self::from_slice(slice)
 	}
+	#[inline]
+	fn copy_to_slice(self, slice: &mut [f32]) {
+		self.copy_to_slice(slice);
+	}
 
 	#[inline]
 	fn gather_or(slice: &[f32], idxs: Simd<usize, N>, or: Self) -> Self
@@ -82,6 +106,19 @@ where
 		Self::gather_select(slice, enable, idxs, or)
 	}
 	#[inline]
+	fn gather_select_m(
+		slice: &[f32],
+		enable: Mask<i32, N>,
+		idxs: Simd<usize, N>,
+		or: Self,
+	) -> Self {
+		Self::gather_select(slice, enable.cast(), idxs, or)
+	}
+	#[inline]
+	fn load_or(slice: &[f32], enable: Mask<i32, N>, or: Self) -> Self {
+		Self::load_select(slice, enable, or)
+	}
+	#[inline]
 	fn scatter(self, slice: &mut [f32], idxs: Simd<usize, N>)
 	where
 		LaneCount<N>: SupportedLaneCount,
@@ -95,6 +132,32 @@ where
 	{
 		self.scatter_select(slice, enable, idxs);
 	}
+	#[inline]
+	fn scatter_select_m(self, slice: &mut [f32], enable: Mask<i32, N>, idxs: Simd<usize, N>) {
+		self.scatter_select(slice, enable.cast(), idxs);
+	}
+	#[inline]
+	fn store_select(self, slice: &mut [f32], enable: Mask<i32, N>) {
+		self.store_select(slice, enable);
+	}
+	#[allow(clippy::cast_precision_loss)]
+	#[inline]
+	fn accumulate_histogram(self, bins: &mut [u32], min: f32, max: f32) {
+		assert!(min < max, "min must be less than max");
+		assert!((max - min).is_finite(), "max - min must be finite");
+		let Some(last) = bins.len().checked_sub(1) else {
+			return;
+		};
+		let scale = Self::splat(bins.len() as f32 / (max - min));
+		let idxs = SimdReal::floor((self - Self::splat(min)) * scale).cast::<usize>();
+		let idxs = idxs.simd_min(Simd::splat(last));
+		let nans = SimdFloat::is_nan(self).to_array();
+		for (idx, nan) in idxs.to_array().into_iter().zip(nans) {
+			if !nan {
+				bins[idx] = bins[idx].checked_add(1).expect("count overflow");
+			}
+		}
+	}
 
 	#[inline]
 	fn from_bits(bits: Self::Bits) -> Self {
@@ -104,6 +167,15 @@ where
 	fn to_bits(self) -> Self::Bits {
 		SimdFloat::to_bits(self)
 	}
+	#[inline]
+	fn simd_to_fixed(self, frac_bits: u32) -> Self::Bits {
+		let ulp = Self::splat(Real::from_fixed(1, frac_bits));
+		SimdReal::round(self / ulp).cast()
+	}
+	#[inline]
+	fn simd_from_fixed(bits: Self::Bits, frac_bits: u32) -> Self {
+		SimdUint::cast(bits) * Self::splat(Real::from_fixed(1, frac_bits))
+	}
 
 	#[inline]
 	fn reduce_sum(self) -> f32 {
@@ -230,11 +302,39 @@ where
 	fn simd_clamp(self, min: Self, max: Self) -> Self {
 		SimdFloat::simd_clamp(self, min, max)
 	}
+	#[inline]
+	fn simd_clamp_total(self, min: Self, max: Self) -> Self {
+		let key = |real: Self| {
+			let bits = SimdUint::cast::<i32>(SimdFloat::to_bits(real));
+			bits ^ (bits >> 31 & Simd::splat(i32::MAX))
+		};
+		let (key, min_key, max_key) = (key(self), key(min), key(max));
+		assert!(min_key.simd_le(max_key).all(), "min > max in total order");
+		let clamped = key.simd_lt(min_key).select(min, self);
+		key.simd_gt(max_key).select(max, clamped)
+	}
 
 	#[inline]
 	fn recip(self) -> Self {
 		SimdFloat::recip(self)
 	}
+	#[inline]
+	fn simd_div_refined(self, divisor: Self) -> Self {
+		let abs = SimdFloat::abs(divisor);
+		let min = Self::splat(f32::MIN_POSITIVE);
+		let max = Self::splat(f32::MAX / 16.0);
+		if (SimdPartialOrd::simd_ge(abs, min) & SimdPartialOrd::simd_le(abs, max)).all() {
+			let bits = Simd::splat(0x7ef3_11c7) - SimdFloat::to_bits(abs);
+			let mut recip = SimdFloat::copysign(SimdFloat::from_bits(bits), divisor);
+			for _ in 0..3 {
+				let error = SimdReal::mul_add(-divisor, recip, Self::splat(1.0));
+				recip = SimdReal::mul_add(recip, error, recip);
+			}
+			self * recip
+		} else {
+			self / divisor
+		}
+	}
 
 	#[inline]
 	fn to_degrees(self) -> Self {
@@ -245,6 +345,60 @@ where
 		SimdFloat::to_radians(self)
 	}
 
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn sin(self) -> Self {
+		self.to_array().map(Real::sin).into()
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn sin(self) -> Self {
+		SimdReal::sin_cos(self).0
+	}
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn cos(self) -> Self {
+		self.to_array().map(Real::cos).into()
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn cos(self) -> Self {
+		SimdReal::sin_cos(self).1
+	}
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn sin_cos(self) -> (Self, Self) {
+		(SimdReal::sin(self), SimdReal::cos(self))
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn sin_cos(self) -> (Self, Self) {
+		sin_cos_reduced(
+			self,
+			[
+				core::f32::consts::FRAC_PI_2,
+				-4.371_139e-8,
+				-1.715_124_5e-15,
+			],
+			&[-1.666_665_5e-1, 8.332_161e-3, -1.951_529_6e-4],
+			&[4.166_664_6e-2, -1.388_731_6e-3, 2.443_315_7e-5],
+		)
+	}
+	#[inline]
+	fn atan2_fast(self, other: Self) -> Self {
+		atan2_reduced(
+			self,
+			other,
+			&[
+				0.999_977_26,
+				-0.332_623_47,
+				0.193_543_46,
+				-0.116_432_87,
+				0.052_653_32,
+				-0.011_721_2,
+			],
+		)
+	}
 	#[cfg(feature = "libm")]
 	#[inline]
 	fn mul_add(self, a: Self, b: Self) -> Self {
@@ -264,6 +418,12 @@ where
 	fn sqrt(self) -> Self {
 		StdFloat::sqrt(self)
 	}
+	#[inline]
+	fn recip_sqrt_approx(self) -> Self {
+		let valid = SimdFloat::is_normal(self) & SimdFloat::is_sign_positive(self);
+		let bits = Simd::splat(0x5f37_5a86) - (SimdFloat::to_bits(self) >> Simd::splat(1));
+		valid.select(SimdFloat::from_bits(bits), SimdReal::recip_sqrt(self))
+	}
 	#[cfg(feature = "libm")]
 	#[inline]
 	fn floor(self) -> Self {
@@ -336,3 +496,13 @@ where
 			.all()
 	}
 }
+
+impl<const N: usize> ApproxEqRel<f32> for Simd<f32, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn approx_eq_rel(&self, other: &Self, rel: f32) -> bool {
+		self.simd_approx_eq_rel(*other, Self::splat(rel)).all()
+	}
+}
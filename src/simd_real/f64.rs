@@ -4,15 +4,15 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use super::{ApproxEq, Select, SimdReal};
+use super::{atan2_reduced, ApproxEq, ApproxEqRel, Real, Select, SimdReal};
 use core::simd::{
-	cmp::{SimdPartialEq, SimdPartialOrd},
-	num::SimdFloat,
+	cmp::{SimdOrd, SimdPartialEq, SimdPartialOrd},
+	num::{SimdFloat, SimdUint},
 	LaneCount, Mask, Simd, SupportedLaneCount, Swizzle,
 };
 
-#[cfg(feature = "libm")]
-use super::Real;
+#[cfg(not(feature = "libm"))]
+use super::sin_cos_reduced;
 #[cfg(not(feature = "libm"))]
 use std::simd::StdFloat;
 
@@ -54,11 +54,35 @@ where
 	fn to_array(self) -> [f64; N] {
 		Self::to_array(self)
 	}
+	#[inline]
+	fn cast_f32(self) -> Simd<f32, N> {
+		SimdFloat::cast(self)
+	}
+	#[inline]
+	fn cast_f64(self) -> Self {
+		self
+	}
+	#[inline]
+	fn to_usize_trunc(self) -> Simd<usize, N> {
+		SimdFloat::cast(self)
+	}
+	#[inline]
+	fn to_i32_trunc(self) -> Simd<i32, N> {
+		SimdFloat::cast(self)
+	}
+	#[inline]
+	fn to_i64_trunc(self) -> Simd<i64, N> {
+		SimdFloat::cast(self)
+	}
 
 	#[inline]
 	fn from_slice(slice: &[f64]) -> Self {
 		Self::from_slice(slice)
 	}
+	#[inline]
+	fn copy_to_slice(self, slice: &mut [f64]) {
+		self.copy_to_slice(slice);
+	}
 
 	#[inline]
 	fn gather_or(slice: &[f64], idxs: Simd<usize, N>, or: Self) -> Self
@@ -82,6 +106,19 @@ where
 		Self::gather_select(slice, enable, idxs, or)
 	}
 	#[inline]
+	fn gather_select_m(
+		slice: &[f64],
+		enable: Mask<i64, N>,
+		idxs: Simd<usize, N>,
+		or: Self,
+	) -> Self {
+		Self::gather_select(slice, enable.cast(), idxs, or)
+	}
+	#[inline]
+	fn load_or(slice: &[f64], enable: Mask<i64, N>, or: Self) -> Self {
+		Self::load_select(slice, enable, or)
+	}
+	#[inline]
 	fn scatter(self, slice: &mut [f64], idxs: Simd<usize, N>)
 	where
 		LaneCount<N>: SupportedLaneCount,
@@ -95,6 +132,32 @@ where
 	{
 		self.scatter_select(slice, enable, idxs);
 	}
+	#[inline]
+	fn scatter_select_m(self, slice: &mut [f64], enable: Mask<i64, N>, idxs: Simd<usize, N>) {
+		self.scatter_select(slice, enable.cast(), idxs);
+	}
+	#[inline]
+	fn store_select(self, slice: &mut [f64], enable: Mask<i64, N>) {
+		self.store_select(slice, enable);
+	}
+	#[allow(clippy::cast_precision_loss)]
+	#[inline]
+	fn accumulate_histogram(self, bins: &mut [u32], min: f64, max: f64) {
+		assert!(min < max, "min must be less than max");
+		assert!((max - min).is_finite(), "max - min must be finite");
+		let Some(last) = bins.len().checked_sub(1) else {
+			return;
+		};
+		let scale = Self::splat(bins.len() as f64 / (max - min));
+		let idxs = SimdReal::floor((self - Self::splat(min)) * scale).cast::<usize>();
+		let idxs = idxs.simd_min(Simd::splat(last));
+		let nans = SimdFloat::is_nan(self).to_array();
+		for (idx, nan) in idxs.to_array().into_iter().zip(nans) {
+			if !nan {
+				bins[idx] = bins[idx].checked_add(1).expect("count overflow");
+			}
+		}
+	}
 
 	#[inline]
 	fn from_bits(bits: Self::Bits) -> Self {
@@ -104,6 +167,15 @@ where
 	fn to_bits(self) -> Self::Bits {
 		SimdFloat::to_bits(self)
 	}
+	#[inline]
+	fn simd_to_fixed(self, frac_bits: u32) -> Self::Bits {
+		let ulp = Self::splat(Real::from_fixed(1, frac_bits));
+		SimdReal::round(self / ulp).cast()
+	}
+	#[inline]
+	fn simd_from_fixed(bits: Self::Bits, frac_bits: u32) -> Self {
+		SimdUint::cast(bits) * Self::splat(Real::from_fixed(1, frac_bits))
+	}
 
 	#[inline]
 	fn reduce_sum(self) -> f64 {
@@ -230,11 +302,39 @@ where
 	fn simd_clamp(self, min: Self, max: Self) -> Self {
 		SimdFloat::simd_clamp(self, min, max)
 	}
+	#[inline]
+	fn simd_clamp_total(self, min: Self, max: Self) -> Self {
+		let key = |real: Self| {
+			let bits = SimdUint::cast::<i64>(SimdFloat::to_bits(real));
+			bits ^ (bits >> 63 & Simd::splat(i64::MAX))
+		};
+		let (key, min_key, max_key) = (key(self), key(min), key(max));
+		assert!(min_key.simd_le(max_key).all(), "min > max in total order");
+		let clamped = key.simd_lt(min_key).select(min, self);
+		key.simd_gt(max_key).select(max, clamped)
+	}
 
 	#[inline]
 	fn recip(self) -> Self {
 		SimdFloat::recip(self)
 	}
+	#[inline]
+	fn simd_div_refined(self, divisor: Self) -> Self {
+		let abs = SimdFloat::abs(divisor);
+		let min = Self::splat(f64::MIN_POSITIVE);
+		let max = Self::splat(f64::MAX / 16.0);
+		if (SimdPartialOrd::simd_ge(abs, min) & SimdPartialOrd::simd_le(abs, max)).all() {
+			let bits = Simd::splat(0x7fde_6238_22fc_16e6) - SimdFloat::to_bits(abs);
+			let mut recip = SimdFloat::copysign(SimdFloat::from_bits(bits), divisor);
+			for _ in 0..4 {
+				let error = SimdReal::mul_add(-divisor, recip, Self::splat(1.0));
+				recip = SimdReal::mul_add(recip, error, recip);
+			}
+			self * recip
+		} else {
+			self / divisor
+		}
+	}
 
 	#[inline]
 	fn to_degrees(self) -> Self {
@@ -245,6 +345,74 @@ where
 		SimdFloat::to_radians(self)
 	}
 
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn sin(self) -> Self {
+		self.to_array().map(Real::sin).into()
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn sin(self) -> Self {
+		SimdReal::sin_cos(self).0
+	}
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn cos(self) -> Self {
+		self.to_array().map(Real::cos).into()
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn cos(self) -> Self {
+		SimdReal::sin_cos(self).1
+	}
+	#[cfg(feature = "libm")]
+	#[inline]
+	fn sin_cos(self) -> (Self, Self) {
+		(SimdReal::sin(self), SimdReal::cos(self))
+	}
+	#[cfg(not(feature = "libm"))]
+	#[inline]
+	fn sin_cos(self) -> (Self, Self) {
+		sin_cos_reduced(
+			self,
+			[
+				core::f64::consts::FRAC_PI_2,
+				6.123_233_995_736_766e-17,
+				-1.497_384_904_859_169_8e-33,
+			],
+			&[
+				-1.666_666_666_666_663_2e-1,
+				8.333_333_333_322_49e-3,
+				-1.984_126_982_985_795e-4,
+				2.755_731_370_707_006_8e-6,
+				-2.505_076_025_340_686_3e-8,
+				1.589_690_995_211_55e-10,
+			],
+			&[
+				4.166_666_666_666_66e-2,
+				-1.388_888_888_887_411e-3,
+				2.480_158_728_947_673e-5,
+				-2.755_731_435_139_066_3e-7,
+				2.087_572_321_298_175e-9,
+				-1.135_964_755_778_819_5e-11,
+			],
+		)
+	}
+	#[inline]
+	fn atan2_fast(self, other: Self) -> Self {
+		atan2_reduced(
+			self,
+			other,
+			&[
+				0.999_977_26,
+				-0.332_623_47,
+				0.193_543_46,
+				-0.116_432_87,
+				0.052_653_32,
+				-0.011_721_2,
+			],
+		)
+	}
 	#[cfg(feature = "libm")]
 	#[inline]
 	fn mul_add(self, a: Self, b: Self) -> Self {
@@ -264,6 +432,13 @@ where
 	fn sqrt(self) -> Self {
 		StdFloat::sqrt(self)
 	}
+	#[inline]
+	fn recip_sqrt_approx(self) -> Self {
+		let valid = SimdFloat::is_normal(self) & SimdFloat::is_sign_positive(self);
+		let bits =
+			Simd::splat(0x5fe6_eb50_c7b5_37a9) - (SimdFloat::to_bits(self) >> Simd::splat(1));
+		valid.select(SimdFloat::from_bits(bits), SimdReal::recip_sqrt(self))
+	}
 	#[cfg(feature = "libm")]
 	#[inline]
 	fn floor(self) -> Self {
@@ -336,3 +511,13 @@ where
 			.all()
 	}
 }
+
+impl<const N: usize> ApproxEqRel<f64> for Simd<f64, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn approx_eq_rel(&self, other: &Self, rel: f64) -> bool {
+		self.simd_approx_eq_rel(*other, Self::splat(rel)).all()
+	}
+}
@@ -6,13 +6,15 @@
 
 // Derivative work of `core::simd` licensed under `MIT OR Apache-2.0`.
 
-use super::{ApproxEq, Real, Select, SimdBits, SimdMask};
+use super::{ApproxEq, ApproxEqRel, Bits, Real, RoundMode, Select, SimdBits, SimdMask};
 use core::{
+	array,
 	fmt::Debug,
+	hash::{Hash, Hasher},
 	iter::{Product, Sum},
 	ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign},
 	ops::{Index, IndexMut},
-	simd::{LaneCount, Mask, Simd, SupportedLaneCount, Swizzle},
+	simd::{cmp::SimdPartialOrd, LaneCount, Mask, Simd, SupportedLaneCount, Swizzle},
 };
 
 mod f32;
@@ -61,7 +63,7 @@ pub trait SimdReal<R: Real, const N: usize>
 where
 	LaneCount<N>: SupportedLaneCount,
 	Self: Send + Sync + Clone + Copy + Default,
-	Self: ApproxEq<R, Self> + PartialEq + PartialOrd,
+	Self: ApproxEq<R, Self> + ApproxEqRel<R, Self> + PartialEq + PartialOrd,
 	Self: Debug,
 	Self: From<Simd<R, N>> + Into<Simd<R, N>>,
 	Self: From<[R; N]> + Into<[R; N]>,
@@ -154,6 +156,133 @@ where
 	/// Converts a SIMD vector to an array.
 	#[must_use]
 	fn to_array(self) -> [R; N];
+	/// Converts a [`Simd`] vector to a SIMD vector.
+	///
+	/// Equals [`From::from()`] but names the conversion.
+	#[must_use]
+	#[inline]
+	fn from_simd(simd: Simd<R, N>) -> Self {
+		simd.into()
+	}
+	/// Converts a SIMD vector to a [`Simd`] vector.
+	///
+	/// Equals [`Into::into()`] but names the conversion. This allows calling [`core::simd`] methods
+	/// not wrapped by this trait in code generic over [`Real`].
+	#[must_use]
+	#[inline]
+	fn to_simd(self) -> Simd<R, N> {
+		self.into()
+	}
+	/// Converts each lane to `f32` like an `as` cast.
+	///
+	/// Narrowing from `f64` rounds to the nearest representable value with ties to even where
+	/// values beyond the range of `f32` become infinite. NaN lanes are preserved as NaN.
+	#[must_use]
+	fn cast_f32(self) -> Simd<f32, N>;
+	/// Converts each lane to `f64` like an `as` cast.
+	///
+	/// Widening from `f32` is exact, i.e., converting back via [`Self::cast_f32()`] yields the
+	/// original lanes. NaN lanes are preserved as NaN.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::{ApproxEq, SimdReal};
+	///
+	/// let x = Simd::<f32, 4>::splat(1.1);
+	/// let y = x.cast_f64();
+	/// assert!(y.approx_eq(&Simd::splat(1.1), f64::from(f32::EPSILON), 0));
+	/// assert_ne!(y, Simd::splat(1.1));
+	/// assert_eq!(y.cast_f32(), x);
+	/// let x = Simd::<f64, 4>::from_array([1.1, f64::NAN, 1e300, -1e-300]);
+	/// let y = x.cast_f32();
+	/// assert!(y[0].approx_eq(&1.1, 0.0, 0) && y[1].is_nan());
+	/// assert_eq!((y[2], y[3]), (f32::INFINITY, -0.0));
+	/// ```
+	#[must_use]
+	fn cast_f64(self) -> Simd<f64, N>;
+	/// Converts each lane to `usize` rounding toward zero like an `as` cast.
+	///
+	/// Lanes beyond the range of `usize` saturate to its minimum or maximum and NaN lanes become
+	/// zero. This produces index vectors for gathers and scatters.
+	#[must_use]
+	fn to_usize_trunc(self) -> Simd<usize, N>;
+	/// Converts each lane to `usize` rounding half-way cases away from zero.
+	///
+	/// Equals `self.round().to_usize_trunc()` and saturates like [`Self::to_usize_trunc()`].
+	#[must_use]
+	#[inline]
+	fn to_usize_round(self) -> Simd<usize, N> {
+		self.round().to_usize_trunc()
+	}
+	/// Converts each lane to `i32` rounding toward zero like an `as` cast.
+	///
+	/// Lanes beyond the range of `i32` saturate to its minimum or maximum and NaN lanes become
+	/// zero.
+	#[must_use]
+	fn to_i32_trunc(self) -> Simd<i32, N>;
+	/// Converts each lane to `i32` rounding half-way cases away from zero.
+	///
+	/// Equals `self.round().to_i32_trunc()` and saturates like [`Self::to_i32_trunc()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 4>::from_array([1.9, -0.4, 2.5, -2.5]);
+	/// assert_eq!(x.to_i32_trunc().to_array(), [1, 0, 2, -2]);
+	/// assert_eq!(x.to_i32_round().to_array(), [2, 0, 3, -3]);
+	/// assert_eq!(x.to_i64_trunc().to_array(), [1, 0, 2, -2]);
+	/// assert_eq!(x.to_i64_round().to_array(), [2, 0, 3, -3]);
+	/// assert_eq!(x.to_usize_trunc().to_array(), [1, 0, 2, 0]);
+	/// assert_eq!(x.to_usize_round().to_array(), [2, 0, 3, 0]);
+	/// let x = Simd::<f64, 4>::from_array([f64::NAN, 1e9, -1e10, f64::INFINITY]);
+	/// assert_eq!(
+	/// 	x.to_i32_trunc().to_array(),
+	/// 	[0, 1_000_000_000, i32::MIN, i32::MAX]
+	/// );
+	/// assert_eq!(
+	/// 	x.to_usize_round().to_array(),
+	/// 	[0, 1_000_000_000, 0, usize::MAX]
+	/// );
+	/// ```
+	#[must_use]
+	#[inline]
+	fn to_i32_round(self) -> Simd<i32, N> {
+		self.round().to_i32_trunc()
+	}
+	/// Converts each lane to `i64` rounding toward zero like an `as` cast.
+	///
+	/// Lanes beyond the range of `i64` saturate to its minimum or maximum and NaN lanes become
+	/// zero.
+	#[must_use]
+	fn to_i64_trunc(self) -> Simd<i64, N>;
+	/// Converts each lane to `i64` rounding half-way cases away from zero.
+	///
+	/// Equals `self.round().to_i64_trunc()` and saturates like [`Self::to_i64_trunc()`].
+	#[must_use]
+	#[inline]
+	fn to_i64_round(self) -> Simd<i64, N> {
+		self.round().to_i64_trunc()
+	}
+	/// Reads the lanes at the given lane indices `idxs` into an array.
+	///
+	/// # Panics
+	///
+	/// Panics if any index is out of bounds, i.e., not less than `N`.
+	#[must_use]
+	#[inline]
+	fn get_lanes<const K: usize>(self, idxs: [usize; K]) -> [R; K] {
+		let array = self.to_array();
+		idxs.map(|idx| array[idx])
+	}
 
 	/// Converts a slice to a SIMD vector containing `slice[..N]`
 	///
@@ -162,6 +291,86 @@ where
 	/// Panics if the slice's `len` is less than the vector's `Simd::N`.
 	#[must_use]
 	fn from_slice(slice: &[R]) -> Self;
+	/// Writes the lanes to `slice[..N]`.
+	///
+	/// This is the inverse of [`Self::from_slice()`].
+	///
+	/// # Panics
+	///
+	/// Panics if the slice's `len` is less than the vector's `Simd::N`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let lanes = [1.0, 2.0, 3.0, 4.0, 5.0];
+	/// let x: Simd<f64, 4> = SimdReal::from_slice(&lanes);
+	/// let mut slice = [0.0; 5];
+	/// SimdReal::copy_to_slice(x, &mut slice);
+	/// assert_eq!(slice, [1.0, 2.0, 3.0, 4.0, 0.0]);
+	/// assert_eq!(x, SimdReal::from_slice(&slice));
+	/// ```
+	///
+	/// ```should_panic
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// // Panics as the slice is too short.
+	/// SimdReal::copy_to_slice(Simd::<f32, 4>::splat(1.0), &mut [0.0; 3]);
+	/// ```
+	fn copy_to_slice(self, slice: &mut [R]);
+	/// Converts a slice to a SIMD vector containing `slice[..N]` padding missing lanes with `pad`.
+	///
+	/// Unlike [`Self::from_slice()`], the slice's `len` may be less than the vector's `Simd::N`.
+	#[must_use]
+	#[inline]
+	fn from_slice_padded(slice: &[R], pad: R) -> Self {
+		Self::from_array(array::from_fn(|lane| {
+			slice.get(lane).copied().unwrap_or(pad)
+		}))
+	}
+	/// Constructs a SIMD vector from the first `N` items of `iter` filling missing lanes with `or`.
+	///
+	/// Consumes at most `N` items, i.e., extra items beyond `N` are ignored and left in `iter`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 4>::from_iter_or([1.0, 2.0], -1.0);
+	/// assert_eq!(x.to_array(), [1.0, 2.0, -1.0, -1.0]);
+	/// let x = Simd::<f32, 4>::from_iter_or([1.0, 2.0, 3.0, 4.0], -1.0);
+	/// assert_eq!(x, Simd::from_array([1.0, 2.0, 3.0, 4.0]));
+	/// let mut iter = (0..6u8).map(f64::from);
+	/// let x = Simd::<f64, 4>::from_iter_or(iter.by_ref(), -1.0);
+	/// assert_eq!(x.to_array(), [0.0, 1.0, 2.0, 3.0]);
+	/// assert_eq!(iter.collect::<Vec<f64>>(), [4.0, 5.0]);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn from_iter_or(iter: impl IntoIterator<Item = R>, or: R) -> Self {
+		let mut iter = iter.into_iter();
+		Self::from_array(array::from_fn(|_lane| iter.next().unwrap_or(or)))
+	}
+	/// Writes the lanes to `slice[..N]` truncating lanes beyond the slice's `len`.
+	///
+	/// Unlike [`Self::copy_to_slice()`], the slice's `len` may be less than the vector's
+	/// `Simd::N`. This is the counterpart of [`Self::from_slice_padded()`].
+	#[inline]
+	fn store_truncated(self, slice: &mut [R]) {
+		let len = slice.len().min(N);
+		slice[..len].copy_from_slice(&self.as_array()[..len]);
+	}
 
 	/// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector.
 	///
@@ -186,6 +395,105 @@ where
 	fn gather_select(slice: &[R], enable: Mask<isize, N>, idxs: Simd<usize, N>, or: Self) -> Self
 	where
 		LaneCount<N>: SupportedLaneCount;
+	/// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector.
+	///
+	/// Like [`Self::gather_select()`] but takes the mask `enable` as [`Self::Mask`] converting it
+	/// to the pointer-sized mask internally.
+	#[must_use]
+	fn gather_select_m(slice: &[R], enable: Self::Mask, idxs: Simd<usize, N>, or: Self) -> Self;
+	/// Reads the leading lanes of `slice` to construct a SIMD vector.
+	///
+	/// The mask `enable`s all `true` lanes and disables all `false` lanes. Only the first
+	/// `slice.len().min(N)` lanes are read, i.e., lanes are disabled and never touch memory if
+	/// they are out-of-bounds. Disabled lanes are selected from the `or` vector. This loads a
+	/// suffix of less than `N` lanes as left by [`Real::as_simd()`] in one vector operation.
+	#[must_use]
+	fn load_or(slice: &[R], enable: Self::Mask, or: Self) -> Self;
+	/// Reads from potentially discontiguous indices in `slice` to construct a SIMD vector.
+	///
+	/// The mask `enable`s all `true` lanes and disables all `false` lanes.
+	/// If an index is disabled or is out-of-bounds, the lane is set to zero.
+	#[must_use]
+	#[inline]
+	fn gather_select_or_zero(slice: &[R], enable: Mask<isize, N>, idxs: Simd<usize, N>) -> Self
+	where
+		LaneCount<N>: SupportedLaneCount,
+	{
+		Self::gather_select(slice, enable, idxs, Self::splat(R::ZERO))
+	}
+	/// Permutes the lanes by the runtime indices `idxs`.
+	///
+	/// Each lane of the result is the lane of `self` selected by the corresponding lane of `idxs`.
+	/// Lanes with out-of-range indices, i.e., not less than `N`, are set to `0.0`. The indices
+	/// are unsigned integers of the same width as the lanes, i.e., `u32` for `f32` and `u64` for
+	/// `f64`. As [`Simd::swizzle_dyn()`] is restricted to byte lanes, this is a gather via
+	/// [`SimdBits::lookup()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 4>::from_array([10.0, 20.0, 30.0, 40.0]);
+	/// let idxs = [3, 0, 2, 1];
+	/// assert_eq!(
+	/// 	x.swizzle_dyn(Simd::from_array(idxs)).to_array(),
+	/// 	[40.0, 10.0, 30.0, 20.0]
+	/// );
+	/// let idxs = [1, 1, 4, u32::MAX];
+	/// assert_eq!(
+	/// 	x.swizzle_dyn(Simd::from_array(idxs)).to_array(),
+	/// 	[20.0, 20.0, 0.0, 0.0]
+	/// );
+	/// let x = Simd::<f64, 4>::from_array([10.0, 20.0, 30.0, 40.0]);
+	/// let idxs = [0, 3, 3, 2];
+	/// assert_eq!(
+	/// 	x.swizzle_dyn(Simd::from_array(idxs)).to_array(),
+	/// 	[10.0, 40.0, 40.0, 30.0]
+	/// );
+	/// ```
+	#[must_use]
+	#[inline]
+	fn swizzle_dyn(self, idxs: Self::Bits) -> Self {
+		Self::from_bits(Self::Bits::lookup(self.to_bits(), idxs))
+	}
+	/// Counts the indices in `idxs` which are in-bounds of `slice`.
+	///
+	/// This is the number of lanes a gather reads from `slice` instead of falling back to the `or`
+	/// vector, without performing the gather itself.
+	#[must_use]
+	#[inline]
+	fn gather_count(slice: &[R], idxs: Simd<usize, N>) -> usize
+	where
+		LaneCount<N>: SupportedLaneCount,
+	{
+		let hits = idxs.simd_lt(Simd::splat(slice.len()));
+		hits.to_array().into_iter().filter(|&hit| hit).count()
+	}
+	/// Wraps each index in `idxs` around `len` computing `idx % len` for cyclic access.
+	///
+	/// Masks the indices by `len - 1` if `len` is a power of two instead of computing the
+	/// remainder.
+	///
+	/// # Panics
+	///
+	/// Panics if `len` is zero.
+	#[must_use]
+	#[inline]
+	fn wrap_indices(idxs: Simd<usize, N>, len: usize) -> Simd<usize, N>
+	where
+		LaneCount<N>: SupportedLaneCount,
+	{
+		assert_ne!(len, 0, "cannot wrap indices around zero length");
+		if len.is_power_of_two() {
+			idxs & Simd::splat(len - 1)
+		} else {
+			idxs % Simd::splat(len)
+		}
+	}
 	/// Writes the values in a SIMD vector to potentially discontiguous indices in `slice`.
 	///
 	/// If two lanes in the scattered vector would write to the same index only the last lane is
@@ -201,6 +509,157 @@ where
 	fn scatter_select(self, slice: &mut [R], enable: Mask<isize, N>, idxs: Simd<usize, N>)
 	where
 		LaneCount<N>: SupportedLaneCount;
+	/// Writes the values in a SIMD vector to multiple potentially discontiguous indices in `slice`.
+	///
+	/// Like [`Self::scatter_select()`] but takes the mask `enable` as [`Self::Mask`] converting it
+	/// to the pointer-sized mask internally.
+	fn scatter_select_m(self, slice: &mut [R], enable: Self::Mask, idxs: Simd<usize, N>);
+	/// Writes the leading lanes of `slice` from a SIMD vector.
+	///
+	/// The mask `enable`s all `true` lanes and disables all `false` lanes. Only the first
+	/// `slice.len().min(N)` lanes are written, i.e., lanes are disabled and never touch memory if
+	/// they are out-of-bounds. This is the inverse of [`Self::load_or()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::{Mask, Simd};
+	/// use lav::SimdReal;
+	///
+	/// let mut memory = [1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0];
+	/// let tail = &mut memory[4..];
+	/// let x: Simd<f32, 4> = SimdReal::load_or(tail, Mask::splat(true), Simd::splat(-1.0));
+	/// assert_eq!(x.to_array(), [5.0, 6.0, 7.0, -1.0]);
+	/// (x * Simd::splat(10.0)).store_select(tail, Mask::splat(true));
+	/// assert_eq!(memory, [1.0, 2.0, 3.0, 4.0, 50.0, 60.0, 70.0]);
+	/// Simd::splat(0.0).store_select(
+	/// 	&mut memory[1..4],
+	/// 	Mask::from_array([true, false, true, true]),
+	/// );
+	/// assert_eq!(memory, [1.0, 0.0, 3.0, 0.0, 50.0, 60.0, 70.0]);
+	/// ```
+	fn store_select(self, slice: &mut [R], enable: Self::Mask);
+	/// Reads every `stride`-th element of `slice` beginning at index `start` to construct a SIMD
+	/// vector.
+	///
+	/// Loads `slice[start + lane * stride]` into each lane, e.g., the $x$ coordinates of a slice
+	/// of $[x, y, z]$ coordinates with `start = 0` and `stride = 3`.
+	///
+	/// # Panics
+	///
+	/// Panics if any index is out-of-bounds.
+	#[must_use]
+	#[inline]
+	fn load_strided(slice: &[R], start: usize, stride: usize) -> Self {
+		Self::gather_or_default(slice, strided_idxs(slice.len(), start, stride))
+	}
+	/// Writes the values in a SIMD vector to every `stride`-th element of `slice` beginning at
+	/// index `start`.
+	///
+	/// Stores each lane into `slice[start + lane * stride]`. This is the counterpart of
+	/// [`Self::load_strided`].
+	///
+	/// # Panics
+	///
+	/// Panics if any index is out-of-bounds.
+	#[inline]
+	fn store_strided(self, slice: &mut [R], start: usize, stride: usize) {
+		self.scatter(slice, strided_idxs(slice.len(), start, stride));
+	}
+	/// Packs the lanes enabled by `mask` into the low lanes preserving their order and returns
+	/// them together with their count.
+	///
+	/// The remaining high lanes are set to zero. Looks up the permutation of lane indices by the
+	/// bitmask of `mask` in a precomputed table for up to 8 lanes and gathers the lanes
+	/// accordingly, where the indices of the remaining lanes are out of bounds. For more lanes,
+	/// the permutation is built lane by lane at runtime instead.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::{Mask, Simd};
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::from_array([1.0f32, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+	/// let mask = Mask::from_array([false, true, true, false, false, true, false, true]);
+	/// let (y, count) = x.compact(mask);
+	/// assert_eq!(y.to_array(), [2.0, 3.0, 6.0, 8.0, 0.0, 0.0, 0.0, 0.0]);
+	/// assert_eq!(count, 4);
+	/// let x = Simd::<f64, 4>::from_array([1.0, 2.0, 3.0, 4.0]);
+	/// let (y, count) = x.compact(Mask::from_array([true, false, false, true]));
+	/// assert_eq!((y.to_array(), count), ([1.0, 4.0, 0.0, 0.0], 2));
+	/// let x = Simd::<f32, 16>::from_array(core::array::from_fn(|lane| lane as f32 + 1.0));
+	/// let (y, count) = x.compact(Mask::from_array(core::array::from_fn(|lane| lane % 3 == 0)));
+	/// assert_eq!(y.to_array()[..6], [1.0, 4.0, 7.0, 10.0, 13.0, 16.0]);
+	/// assert_eq!(y.to_array()[6..], [0.0; 10]);
+	/// assert_eq!(count, 6);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn compact(self, mask: Self::Mask) -> (Self, usize) {
+		let idxs = if N <= COMPACT_LANES {
+			#[allow(clippy::cast_possible_truncation)]
+			let idxs = COMPACT_IDXS[mask.to_bitmask() as usize];
+			Simd::from_array(array::from_fn(|lane| usize::from(idxs[lane])))
+		} else {
+			let mut idxs = [N; N];
+			let mut count = 0;
+			for (lane, enable) in mask.to_array().into_iter().enumerate() {
+				if enable {
+					idxs[count] = lane;
+					count += 1;
+				}
+			}
+			Simd::from_array(idxs)
+		};
+		let compacted = Self::gather_or_default(&self.to_array(), idxs);
+		(compacted, mask.count())
+	}
+	/// Increments the counts of the `bins` the lanes fall into.
+	///
+	/// The bins partition $[min, max)$ into `bins.len()` intervals of equal width where each lane
+	/// falls into the bin of index $\lfloor {x - min \over max - min} \cdot n \rfloor$ with
+	/// `n = bins.len()`. Lanes less than `min` fall into the first bin, lanes greater than or
+	/// equal to `max` into the last bin, whereas NaN lanes are not counted. Lanes falling into the
+	/// same bin increment its count sequentially. Empty `bins` count nothing, i.e., the call only
+	/// checks `min` and `max`.
+	///
+	/// # Panics
+	///
+	/// Panics if `min` is not less than `max`, if `max - min` overflows to infinity, or if a count
+	/// overflows.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let mut bins = [0; 4];
+	/// Simd::from_array([-1.0f32, 0.0, 0.3, 0.3, 0.5, 0.99, 1.0, f32::NAN])
+	/// 	.accumulate_histogram(&mut bins, 0.0, 1.0);
+	/// assert_eq!(bins, [2, 2, 1, 2]);
+	/// // Empty bins count nothing.
+	/// Simd::<f32, 4>::splat(0.5).accumulate_histogram(&mut [], 0.0, 1.0);
+	/// ```
+	///
+	/// ```should_panic
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// // The width of `f64::MAX - f64::MIN` overflows to infinity.
+	/// let mut bins = [0; 4];
+	/// Simd::<f64, 2>::splat(0.0).accumulate_histogram(&mut bins, f64::MIN, f64::MAX);
+	/// ```
+	fn accumulate_histogram(self, bins: &mut [u32], min: R, max: R);
 
 	/// Raw transmutation from an unsigned integer vector type with the same size and number of
 	/// lanes.
@@ -209,6 +668,69 @@ where
 	/// Raw transmutation to an unsigned integer vector type with the same size and number of lanes.
 	#[must_use]
 	fn to_bits(self) -> Self::Bits;
+	/// Returns the canonical bits of each lane.
+	///
+	/// Like [`Self::to_bits()`] but maps `-0.0` to the bits of `+0.0` and NaNs of any sign and
+	/// payload to the bits of [`Real::NAN`], so lanes comparing equal have equal bits and NaNs have
+	/// consistent bits.
+	#[must_use]
+	#[inline]
+	fn canonical_bits(self) -> Self::Bits {
+		let zero = Self::splat(R::ZERO);
+		let canonical = self.simd_eq(zero).select(zero, self);
+		self.is_nan()
+			.select(Self::splat(R::NAN), canonical)
+			.to_bits()
+	}
+	/// Feeds the canonical bits of the lanes into `state`, see [`Self::canonical_bits()`].
+	///
+	/// Vectors of lanes comparing equal hash equally, i.e., `+0.0` and `-0.0` lanes collide, and
+	/// vectors with NaN lanes hash consistently. This allows using vectors as deduplication keys
+	/// despite floating-point numbers not implementing [`Hash`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	/// use std::hash::{DefaultHasher, Hasher};
+	///
+	/// fn hash(x: Simd<f32, 4>) -> u64 {
+	/// 	let mut state = DefaultHasher::new();
+	/// 	x.hash_lanes(&mut state);
+	/// 	state.finish()
+	/// }
+	///
+	/// let a = Simd::from_array([0.0, 1.0, f32::NAN, -2.0]);
+	/// let b = Simd::from_array([-0.0, 1.0, -f32::from_bits(0x7fc0_0001), -2.0]);
+	/// assert_ne!(a.to_bits(), b.to_bits());
+	/// assert_eq!(hash(a), hash(b));
+	/// assert_ne!(hash(a), hash(Simd::from_array([0.0, 1.0, f32::NAN, 2.0])));
+	/// ```
+	#[inline]
+	fn hash_lanes<H: Hasher>(self, state: &mut H) {
+		self.canonical_bits().hash(state);
+	}
+	/// Converts each lane to unsigned fixed-point with `frac_bits` fractional bits.
+	///
+	/// Rounds and saturates like [`Real::to_fixed()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `frac_bits` exceeds the number of bits of [`Real::Bits`].
+	#[must_use]
+	fn simd_to_fixed(self, frac_bits: u32) -> Self::Bits;
+	/// Converts each lane from unsigned fixed-point `bits` with `frac_bits` fractional bits.
+	///
+	/// Rounds like [`Real::from_fixed()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `frac_bits` exceeds the number of bits of [`Real::Bits`].
+	#[must_use]
+	fn simd_from_fixed(bits: Self::Bits, frac_bits: u32) -> Self;
 
 	/// Inserts `value` at `lane`.
 	#[must_use]
@@ -221,6 +743,42 @@ where
 	/// Reducing wrapping add. Returns the sum of the lanes of the vector, with wrapping addition.
 	#[must_use]
 	fn reduce_sum(self) -> R;
+	/// Adds the sum of the lanes enabled by `enable` to the running total `acc`.
+	///
+	/// Disabled lanes contribute zero, so NaN or infinite disabled lanes do not affect `acc`.
+	/// Useful for filtered streaming reductions across many chunks.
+	#[inline]
+	fn add_masked_to(self, acc: &mut R, enable: Self::Mask) {
+		*acc += enable.select(self, Self::splat(R::ZERO)).reduce_sum();
+	}
+	/// Returns the dot product, i.e., the sum of the lanewise products of `self` and `other`.
+	///
+	/// Equals `(self * other).reduce_sum()` by default, whereas implementations may use a fused
+	/// tree reduction instead. Hence, the order of summation and the rounding are unspecified.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let (a, b) = ([1.0, -2.0, 3.5, 0.25], [4.0, 0.5, -2.0, 8.0]);
+	/// let dot = a[0] * b[0] + a[1] * b[1] + a[2] * b[2] + a[3] * b[3];
+	/// assert_eq!(dot, -2.0);
+	/// assert_eq!(Simd::<f32, 4>::from_array(a).dot(Simd::from_array(b)), dot);
+	/// let (a, b) = (a.map(f64::from), b.map(f64::from));
+	/// assert_eq!(
+	/// 	Simd::<f64, 4>::from_array(a).dot(Simd::from_array(b)),
+	/// 	f64::from(dot)
+	/// );
+	/// ```
+	#[must_use]
+	#[inline]
+	fn dot(self, other: Self) -> R {
+		(self * other).reduce_sum()
+	}
 	/// Reducing wrapping multiply. Returns the product of the lanes of the vector, with wrapping
 	/// multiplication.
 	#[must_use]
@@ -237,6 +795,146 @@ where
 	/// either. This function will not return NaN unless all lanes are NaN.
 	#[must_use]
 	fn reduce_max(self) -> R;
+	/// Reducing minimum in accordance to [`Real::total_cmp()`]. Returns the least lane in the
+	/// vector.
+	///
+	/// Unlike [`Self::reduce_min()`], the result is deterministic as `-0.0` is less than `0.0`
+	/// and NaN lanes participate, i.e., a negative NaN is returned if there is any.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 2>::from_array([0.0, -0.0]);
+	/// assert!(x.reduce_total_min().is_sign_negative());
+	/// assert!(x.reduce_total_max().is_sign_positive());
+	/// let x = Simd::<f64, 4>::from_array([1.0, -f64::NAN, f64::NEG_INFINITY, 2.0]);
+	/// assert!(x.reduce_total_min().is_nan());
+	/// assert_eq!(x.reduce_min(), f64::NEG_INFINITY);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn reduce_total_min(self) -> R {
+		self.to_array()
+			.into_iter()
+			.reduce(|min, lane| {
+				if lane.total_cmp(&min).is_lt() {
+					lane
+				} else {
+					min
+				}
+			})
+			.unwrap_or(R::NAN)
+	}
+	/// Reducing maximum in accordance to [`Real::total_cmp()`]. Returns the greatest lane in the
+	/// vector.
+	///
+	/// Unlike [`Self::reduce_max()`], the result is deterministic as `0.0` is greater than
+	/// `-0.0` and NaN lanes participate, i.e., a positive NaN is returned if there is any.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 2>::from_array([-0.0, 0.0]);
+	/// assert!(x.reduce_total_max().is_sign_positive());
+	/// assert!(x.reduce_total_min().is_sign_negative());
+	/// let x = Simd::<f64, 4>::from_array([1.0, f64::NAN, f64::INFINITY, 2.0]);
+	/// assert!(x.reduce_total_max().is_nan());
+	/// assert_eq!(x.reduce_max(), f64::INFINITY);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn reduce_total_max(self) -> R {
+		self.to_array()
+			.into_iter()
+			.reduce(|max, lane| {
+				if lane.total_cmp(&max).is_gt() {
+					lane
+				} else {
+					max
+				}
+			})
+			.unwrap_or(R::NAN)
+	}
+	/// Reducing maximum by key. Returns the maximum lane of `keys` and the lane of `self` at the
+	/// same index.
+	///
+	/// Ties pick the lowest lane index where `0.0` and `-0.0` are considered equal. NaN keys are
+	/// ignored unless all keys are NaN in which case the first lane of `self` is returned.
+	#[must_use]
+	#[inline]
+	fn reduce_max_by_key(self, keys: Self) -> (R, R) {
+		let max = keys.reduce_max();
+		let lane = keys
+			.to_array()
+			.into_iter()
+			.position(|key| key == max)
+			.unwrap_or(0);
+		(max, self[lane])
+	}
+	/// Reducing by `f`. Folds the lanes in index order starting with the first lane as the
+	/// initial accumulator.
+	///
+	/// Calls `f(accumulator, lane)` sequentially for all but the first lane, so the result is
+	/// deterministic.
+	#[must_use]
+	#[inline]
+	fn reduce(self, f: impl FnMut(R, R) -> R) -> R {
+		let array = self.to_array();
+		array[1..].iter().copied().fold(array[0], f)
+	}
+	/// Reducing by `reduce` after mapping each lane by `map`.
+	///
+	/// Calls `map` on each lane in index order followed by folding them like [`Self::reduce()`],
+	/// so the result is deterministic. The closures are called per lane and not vectorized.
+	#[must_use]
+	#[inline]
+	fn map_reduce(self, map: impl FnMut(R) -> R, reduce: impl FnMut(R, R) -> R) -> R {
+		Self::from_array(self.to_array().map(map)).reduce(reduce)
+	}
+	/// Reducing natural logarithm of the sum of the exponentials of all lanes,
+	/// $\ln \sum_{i} e^{x_{i}}$.
+	///
+	/// Subtracts the maximum lane before exponentiating, see [`Real::logaddexp()`]. Returns
+	/// [`Real::NEG_INFINITY`] if all lanes are [`Real::NEG_INFINITY`], [`Real::INFINITY`] if any
+	/// lane is [`Real::INFINITY`], and NaN if any lane is NaN.
+	#[must_use]
+	#[inline]
+	fn reduce_logsumexp(self) -> R {
+		let max = self.reduce_max();
+		if self.is_nan().any() {
+			R::NAN
+		} else if max.is_infinite() {
+			max
+		} else {
+			max + self
+				.map_reduce(|lane| (lane - max).exp(), |sum, exp| sum + exp)
+				.ln()
+		}
+	}
+	/// Reducing geometric mean. Returns the `N`-th root of the product of all lanes.
+	///
+	/// Computes $\exp({1 \over N} \sum_{i} \ln x_{i})$ instead of the `N`-th root of the product
+	/// which overflows or underflows for many lanes of large or small magnitude. Returns NaN if
+	/// any lane is negative or NaN or if zero and infinite lanes are mixed, otherwise zero if any
+	/// lane is zero.
+	#[allow(clippy::cast_possible_truncation)]
+	#[must_use]
+	#[inline]
+	fn reduce_geometric_mean(self) -> R {
+		// SIMD vectors have at most 64 lanes.
+		let n = R::from(N as u8);
+		(self.map_reduce(Real::ln, |sum, ln| sum + ln) / n).exp()
+	}
 
 	/// Reverse the order of the lanes in the vector.
 	#[must_use]
@@ -244,11 +942,37 @@ where
 	/// Rotates the vector such that the first `OFFSET` lanes of the slice move to the end while
 	/// the last `Self::N - OFFSET` lanes move to the front. The lane previously in lane
 	/// `OFFSET` will become the first lane in the slice.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 4>::from_array([1.0, 2.0, 3.0, 4.0]);
+	/// assert_eq!(x.simd_rotate_left::<1>().to_array(), [2.0, 3.0, 4.0, 1.0]);
+	/// assert_eq!(x.simd_rotate_left::<4>(), x);
+	/// ```
 	#[must_use]
 	fn simd_rotate_left<const OFFSET: usize>(self) -> Self;
 	/// Rotates the vector such that the first `Self::N - OFFSET` lanes of the vector move to
 	/// the end while the last `OFFSET` lanes move to the front. The lane previously at index
 	/// `Self::N - OFFSET` will become the first lane in the slice.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 4>::from_array([1.0, 2.0, 3.0, 4.0]);
+	/// assert_eq!(x.simd_rotate_right::<1>().to_array(), [4.0, 1.0, 2.0, 3.0]);
+	/// assert_eq!(x.simd_rotate_right::<1>().simd_rotate_left::<1>(), x);
+	/// ```
 	#[must_use]
 	fn simd_rotate_right<const OFFSET: usize>(self) -> Self;
 	/// Interleaves two vectors.
@@ -271,6 +995,84 @@ where
 	/// second lane.
 	#[must_use]
 	fn deinterleave(self, other: Self) -> (Self, Self);
+	/// Interleaves two vectors into an array of `M = 2 * Self::N` lanes taken alternately from
+	/// `a` and `b`, starting with the first lane of `a`.
+	///
+	/// Fails to compile unless `M = 2 * Self::N`.
+	#[must_use]
+	#[inline]
+	fn interleave_into<const M: usize>(a: Self, b: Self) -> [R; M] {
+		const { assert!(M == 2 * N, "array length must be twice the lane count") };
+		let (first, second) = a.interleave(b);
+		let mut array = [R::ZERO; M];
+		array[..N].copy_from_slice(first.as_array());
+		array[N..].copy_from_slice(second.as_array());
+		array
+	}
+	/// Deinterleaves an array of `M = 2 * Self::N` lanes into two vectors.
+	///
+	/// The first result takes every other lane starting with the first lane, the second result
+	/// takes every other lane starting with the second lane.
+	///
+	/// Fails to compile unless `M = 2 * Self::N`.
+	#[must_use]
+	#[inline]
+	fn deinterleave_from<const M: usize>(array: [R; M]) -> (Self, Self) {
+		const { assert!(M == 2 * N, "array length must be twice the lane count") };
+		Self::from_slice(&array[..N]).deinterleave(Self::from_slice(&array[N..]))
+	}
+	/// Splits two consecutive vectors of interleaved complex numbers into their real and
+	/// imaginary parts.
+	///
+	/// Takes the lanes `[re0, im0, re1, im1, ...]` of `interleaved_lo` followed by those of
+	/// `interleaved_hi` and returns the vectors `(reals, imags)`. This is [`Self::deinterleave()`]
+	/// and the inverse of [`Self::interleave_complex()`].
+	#[must_use]
+	#[inline]
+	fn split_complex(interleaved_lo: Self, interleaved_hi: Self) -> (Self, Self) {
+		interleaved_lo.deinterleave(interleaved_hi)
+	}
+	/// Interleaves the real parts `reals` and imaginary parts `imags` into two consecutive vectors
+	/// of complex numbers.
+	///
+	/// Returns the lanes `[re0, im0, re1, im1, ...]` split into a low and a high vector. This is
+	/// [`Self::interleave()`] and the inverse of [`Self::split_complex()`].
+	#[must_use]
+	#[inline]
+	fn interleave_complex(reals: Self, imags: Self) -> (Self, Self) {
+		reals.interleave(imags)
+	}
+	/// Returns the rows `[cos, -sin]` and `[sin, cos]` of the 2D rotation matrix by `angle`.
+	///
+	/// Computes the cosine of lane 0 and the sine of lane 1 of `angle` at once via the vectorized
+	/// [`Self::sin_cos()`], hence expects both lanes to be equal, e.g., via [`Self::splat()`].
+	///
+	/// Fails to compile unless `Self::N = 2`.
+	#[must_use]
+	#[inline]
+	fn rotation_2d(angle: Self) -> [Self; 2] {
+		const { assert!(N == 2, "lane count must be two") };
+		let (sin, cos) = angle.sin_cos();
+		let cos_sin = Self::mask_flag(0, true).select(cos, sin);
+		[Self::mask_flag(1, true).negate(cos_sin), cos_sin.reverse()]
+	}
+	/// Swaps adjacent lanes, i.e., lanes `(0, 1)`, `(2, 3)`, and so on.
+	///
+	/// Requires `Self::N` to be a power of two.
+	#[must_use]
+	#[inline]
+	fn swap_adjacent(self) -> Self {
+		self.swap_stride::<1>()
+	}
+	/// Swaps lanes separated by stride `S`, i.e., lane `i` with lane `i ^ S`.
+	///
+	/// Requires `Self::N` and `S` to be powers of two with `S < Self::N`, otherwise fails to
+	/// compile. This is the shuffle of a radix-2 butterfly stage.
+	#[must_use]
+	#[inline]
+	fn swap_stride<const S: usize>(self) -> Self {
+		self.swizzle::<SwapStride<S>>()
+	}
 
 	/// Creates new vector by copying lanes from selected lanes of `self`.
 	#[must_use]
@@ -294,9 +1096,61 @@ where
 	fn simd_approx_ne(self, other: Self, epsilon: Self, ulp: Self::Bits) -> Self::Mask {
 		!self.simd_approx_eq(other, epsilon, ulp)
 	}
-
-	/// Test if each lane is equal to the corresponding lane in `other`.
-	#[must_use]
+	/// Returns the lane mask of approximate equality wrt `epsilon` or `ulp`.
+	///
+	/// Equals [`Self::simd_approx_eq()`] whereas [`ApproxEq::approx_eq()`] tests whether all lanes
+	/// are approximately equal. Inspect the mask to find the lanes which drifted apart.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::from_array([1.0f32, 2.0, 3.0, 4.0]);
+	/// let y = Simd::from_array([1.0f32, 2.1, 3.0, 4.5]);
+	/// let (epsilon, ulp) = (Simd::splat(f32::EPSILON), Simd::splat(4));
+	/// let eq = x.approx_eq_mask(y, epsilon, ulp);
+	/// assert_eq!(eq.to_array(), [true, false, true, false]);
+	/// assert_eq!(x.approx_ne_mask(y, epsilon, ulp), !eq);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn approx_eq_mask(self, other: Self, epsilon: Self, ulp: Self::Bits) -> Self::Mask {
+		self.simd_approx_eq(other, epsilon, ulp)
+	}
+	/// Returns the lane mask of approximate inequality wrt `epsilon` and `ulp`.
+	///
+	/// Equals [`Self::simd_approx_ne()`] whereas [`ApproxEq::approx_ne()`] tests whether any lane
+	/// is approximately unequal. Inspect the mask to find the lanes which drifted apart.
+	#[must_use]
+	#[inline]
+	fn approx_ne_mask(self, other: Self, epsilon: Self, ulp: Self::Bits) -> Self::Mask {
+		self.simd_approx_ne(other, epsilon, ulp)
+	}
+	/// Tests lanes for approximate equality wrt the relative tolerance `rel`.
+	///
+	/// Lanes are equal if `|a - b| <= rel * max(|a|, |b|)`, see [`ApproxEqRel`] for zero,
+	/// infinite, and NaN lanes. The lanes of `rel` are usually small multiples of
+	/// [`Real::EPSILON`].
+	#[must_use]
+	#[inline]
+	fn simd_approx_eq_rel(self, other: Self, rel: Self) -> Self::Mask {
+		let diff = (self - other).abs();
+		self.simd_eq(other)
+			| diff.is_finite() & diff.simd_le(rel * self.abs().simd_max(other.abs()))
+	}
+	/// Tests lanes for approximate inequality wrt the relative tolerance `rel`.
+	#[must_use]
+	#[inline]
+	fn simd_approx_ne_rel(self, other: Self, rel: Self) -> Self::Mask {
+		!self.simd_approx_eq_rel(other, rel)
+	}
+
+	/// Test if each lane is equal to the corresponding lane in `other`.
+	#[must_use]
 	fn simd_eq(self, other: Self) -> Self::Mask;
 	/// Test if each lane is not equal to the corresponding lane in `other`.
 	#[must_use]
@@ -338,23 +1192,210 @@ where
 	/// NaN.
 	#[must_use]
 	fn is_normal(self) -> Self::Mask;
+	/// Returns true for each lane if its value is a normal number, i.e., a finite nonzero number
+	/// that is not subnormal.
+	///
+	/// Equivalent to [`Self::is_normal()`] but implemented as a single range check. Returns false
+	/// for zero and subnormals as for infinity and NaN.
+	#[must_use]
+	#[inline]
+	fn simd_is_regular(self) -> Self::Mask {
+		let abs = self.abs();
+		abs.simd_ge(Self::splat(R::MIN_POSITIVE)) & abs.simd_le(Self::splat(R::MAX))
+	}
+	/// Returns true for each lane if its value is zero of either sign.
+	///
+	/// Equals `self.simd_eq(Self::splat(R::ZERO))` as IEEE 754 considers `-0.0` and `+0.0` equal.
+	/// See [`Self::simd_is_positive_zero()`] and [`Self::simd_is_negative_zero()`] to tell them
+	/// apart.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 4>::from_array([0.0, -0.0, 1.0, f32::NAN]);
+	/// assert_eq!(x.simd_is_zero().to_array(), [true, true, false, false]);
+	/// assert_eq!(
+	/// 	x.simd_is_positive_zero().to_array(),
+	/// 	[true, false, false, false]
+	/// );
+	/// assert_eq!(
+	/// 	x.simd_is_negative_zero().to_array(),
+	/// 	[false, true, false, false]
+	/// );
+	/// let x = Simd::<f64, 4>::from_array([0.0, -0.0, 1.0, -f64::MIN_POSITIVE]);
+	/// assert_eq!(x.simd_is_zero().to_array(), [true, true, false, false]);
+	/// assert_eq!(
+	/// 	x.simd_is_positive_zero().to_array(),
+	/// 	[true, false, false, false]
+	/// );
+	/// assert_eq!(
+	/// 	x.simd_is_negative_zero().to_array(),
+	/// 	[false, true, false, false]
+	/// );
+	/// ```
+	#[must_use]
+	#[inline]
+	fn simd_is_zero(self) -> Self::Mask {
+		self.simd_eq(Self::splat(R::ZERO))
+	}
+	/// Returns true for each lane if its value is `+0.0`.
+	///
+	/// Compares the bit patterns as IEEE 754 considers `-0.0` and `+0.0` equal.
+	#[must_use]
+	#[inline]
+	fn simd_is_positive_zero(self) -> Self::Mask {
+		self.to_bits().simd_eq(Self::splat(R::ZERO).to_bits())
+	}
+	/// Returns true for each lane if its value is `-0.0`.
+	///
+	/// Compares the bit patterns as IEEE 754 considers `-0.0` and `+0.0` equal.
+	#[must_use]
+	#[inline]
+	fn simd_is_negative_zero(self) -> Self::Mask {
+		self.to_bits().simd_eq(Self::splat(-R::ZERO).to_bits())
+	}
 
 	/// Produces a vector where every lane has the absolute value of the equivalently-indexed lane
 	/// in `self`.
 	#[must_use]
 	fn abs(self) -> Self;
+	/// Clears the sign bit of each lane.
+	///
+	/// Equals `Self::from_bits(self.to_bits() & !sign_mask)`, i.e., turns `-0.0` into `+0.0` and
+	/// keeps the payload of NaN lanes bit-exact with a cleared sign bit. Unlike [`Self::abs()`],
+	/// this is guaranteed to be a plain bit mask without special cases.
+	#[must_use]
+	#[inline]
+	fn abs_bits(self) -> Self {
+		Self::from_bits(self.to_bits() & Self::Bits::splat(!(-R::ZERO).to_bits()))
+	}
 	/// Replaces each lane with a number that represents its sign.
 	///
 	///   * returns `1.0` if the number is positive, `+0.0`, or [`Real::INFINITY`].
 	///   * returns `-1.0` if the number is negative, `-0.0`, or [`Real::NEG_INFINITY`].
 	///   * returns [`Real::NAN`] if the number is NaN.
+	///
+	/// Zeros keep their sign as required by IEEE 754, see [`Self::signum_zero()`] otherwise.
 	#[must_use]
 	fn signum(self) -> Self;
+	/// Replaces each lane with a number that represents its sign or the lane of `zero_sign` if it
+	/// is zero.
+	///
+	///   * returns `1.0` if the number is positive or [`Real::INFINITY`].
+	///   * returns `-1.0` if the number is negative or [`Real::NEG_INFINITY`].
+	///   * returns the lane of `zero_sign` if the number is `+0.0` or `-0.0`.
+	///   * returns [`Real::NAN`] if the number is NaN.
+	#[must_use]
+	#[inline]
+	fn signum_or(self, zero_sign: Self) -> Self {
+		self.simd_eq(Self::splat(R::ZERO))
+			.select(zero_sign, self.signum())
+	}
+	/// Replaces each lane with a number that represents its sign or zero if it is zero.
+	///
+	///   * returns `1.0` if the number is positive or [`Real::INFINITY`].
+	///   * returns `-1.0` if the number is negative or [`Real::NEG_INFINITY`].
+	///   * returns `0.0` if the number is `+0.0` or `-0.0`.
+	///   * returns [`Real::NAN`] if the number is NaN.
+	///
+	/// Unlike [`Self::signum()`], maps both zeros to `0.0` like [`Real::signum_zero()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use lav::{Real, SimdReal};
+	///
+	/// fn check<R: Real>() {
+	/// 	let three = R::from(3u8);
+	/// 	let x = R::Simd::<8>::from_array([
+	/// 		R::ZERO,
+	/// 		-R::ZERO,
+	/// 		three,
+	/// 		-three,
+	/// 		R::INFINITY,
+	/// 		R::NEG_INFINITY,
+	/// 		R::NAN,
+	/// 		R::ONE,
+	/// 	]);
+	/// 	let y = SimdReal::signum_zero(x).to_array();
+	/// 	assert_eq!(y[0].to_bits(), R::ZERO.to_bits());
+	/// 	assert_eq!(y[1].to_bits(), R::ZERO.to_bits());
+	/// 	assert_eq!(y[2..6], [R::ONE, -R::ONE, R::ONE, -R::ONE]);
+	/// 	assert!(y[6].is_nan());
+	/// 	assert_eq!(y[7], R::ONE);
+	/// 	for (&x, &y) in x.as_array().iter().zip(&y) {
+	/// 		assert!(x.signum_zero().total_cmp(&y).is_eq());
+	/// 	}
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[must_use]
+	#[inline]
+	fn signum_zero(self) -> Self {
+		self.signum_or(Self::splat(R::ZERO))
+	}
 	/// Returns each lane with the magnitude of `self` and the sign of `sign`.
 	///
 	/// If any lane is a [`Real::NAN`], then a [`Real::NAN`] with the sign of `sign` is returned.
 	#[must_use]
 	fn copysign(self, sign: Self) -> Self;
+	/// Returns each lane with the sign of `self` and the magnitude of `magnitude`.
+	///
+	/// Equals `magnitude.copysign(self)` with reversed argument order, i.e., stamps precomputed
+	/// magnitudes with the sign pattern of `self`. The sign of NaN lanes in `self` is used as well.
+	#[must_use]
+	#[inline]
+	fn with_magnitude(self, magnitude: Self) -> Self {
+		magnitude.copysign(self)
+	}
+	/// Returns each lane with the magnitude of `self` and the sign given by the lane mask of
+	/// `negative`.
+	///
+	/// Lanes are negative if their lane mask is true, otherwise positive. Signed zeros and NaNs are
+	/// treated as any other number, i.e., a lane of `0.0` becomes `-0.0` if its lane mask is true.
+	///
+	/// This is a branchless conditional negate of the absolute value, see [`SimdMask::negate`].
+	#[must_use]
+	#[inline]
+	fn simd_with_sign(self, negative: Self::Mask) -> Self {
+		negative.negate(self.abs())
+	}
+	/// Negates each lane if its lane mask of `mask` is true, otherwise keeps it.
+	///
+	/// Equals `mask.negate(self)`, see [`SimdMask::negate`], e.g., for alternating signs of
+	/// series.
+	#[must_use]
+	#[inline]
+	fn simd_negate_if(self, mask: Self::Mask) -> Self {
+		mask.negate(self)
+	}
+	/// Replaces NaN lanes with `nan`, [`Real::INFINITY`] lanes with `pos_inf`, and
+	/// [`Real::NEG_INFINITY`] lanes with `neg_inf` leaving finite lanes untouched.
+	#[must_use]
+	#[inline]
+	fn nan_to_num(self, nan: R, pos_inf: R, neg_inf: R) -> Self {
+		let inf = self
+			.is_sign_negative()
+			.select(Self::splat(neg_inf), Self::splat(pos_inf));
+		let num = self.is_nan().select(Self::splat(nan), self);
+		self.is_infinite().select(inf, num)
+	}
+	/// Replaces NaN lanes with zero, [`Real::INFINITY`] lanes with [`Real::MAX`], and
+	/// [`Real::NEG_INFINITY`] lanes with [`Real::MIN`] leaving finite lanes untouched.
+	#[must_use]
+	#[inline]
+	fn nan_to_num_default(self) -> Self {
+		self.nan_to_num(R::ZERO, R::MAX, R::MIN)
+	}
 	/// Returns the minimum of each lane.
 	///
 	/// If one of the values is [`Real::NAN`], then the other value is returned.
@@ -372,10 +1413,161 @@ where
 	/// than `min`.  Otherwise returns the lane in `self`.
 	#[must_use]
 	fn simd_clamp(self, min: Self, max: Self) -> Self;
+	/// Restrict each lane to a certain interval in accordance to [`Real::total_cmp()`].
+	///
+	/// Handles signed zeros and NaNs like [`Real::clamp_total()`].
+	///
+	/// # Panics
+	///
+	/// Panics if any lane of `min` is greater than the one of `max` in total order.
+	#[must_use]
+	fn simd_clamp_total(self, min: Self, max: Self) -> Self;
+	/// Tests if each lane is less than the corresponding lane in `other` in accordance to
+	/// [`Real::total_cmp()`].
+	///
+	/// Compares the bit patterns as integers with flipped magnitude bits of negative lanes.
+	/// Unlike [`Self::simd_lt()`], `-0.0` is less than `0.0` and NaNs are ordered by their sign
+	/// below negative infinity or above positive infinity.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 4>::from_array([-0.0, -f32::NAN, f32::INFINITY, 1.0]);
+	/// let y = Simd::from_array([0.0, f32::NEG_INFINITY, f32::NAN, 1.0]);
+	/// assert_eq!(x.simd_total_cmp_lt(y).to_array(), [true, true, true, false]);
+	/// assert_eq!(x.simd_lt(y).to_array(), [false, false, false, false]);
+	/// assert_eq!(x.simd_total_cmp_le(y).to_array(), [true, true, true, true]);
+	/// assert_eq!(
+	/// 	x.simd_total_cmp_gt(y).to_array(),
+	/// 	[false, false, false, false]
+	/// );
+	/// assert_eq!(y.simd_total_cmp_ge(x).to_array(), [true, true, true, true]);
+	/// assert_eq!(
+	/// 	x.simd_total_cmp_eq(y).to_array(),
+	/// 	[false, false, false, true]
+	/// );
+	/// assert_eq!(x.simd_eq(y).to_array(), [true, false, false, true]);
+	/// let x = Simd::<f64, 2>::from_array([f64::NAN, -f64::NAN]);
+	/// assert_eq!(x.simd_total_cmp_eq(x).to_array(), [true, true]);
+	/// assert_eq!(x.simd_eq(x).to_array(), [false, false]);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn simd_total_cmp_lt(self, other: Self) -> Self::Mask {
+		total_key(self).simd_lt(total_key(other))
+	}
+	/// Tests if each lane is less than or equal to the corresponding lane in `other` in
+	/// accordance to [`Real::total_cmp()`].
+	///
+	/// See [`Self::simd_total_cmp_lt()`].
+	#[must_use]
+	#[inline]
+	fn simd_total_cmp_le(self, other: Self) -> Self::Mask {
+		total_key(self).simd_le(total_key(other))
+	}
+	/// Tests if each lane is greater than the corresponding lane in `other` in accordance to
+	/// [`Real::total_cmp()`].
+	///
+	/// See [`Self::simd_total_cmp_lt()`].
+	#[must_use]
+	#[inline]
+	fn simd_total_cmp_gt(self, other: Self) -> Self::Mask {
+		total_key(self).simd_gt(total_key(other))
+	}
+	/// Tests if each lane is greater than or equal to the corresponding lane in `other` in
+	/// accordance to [`Real::total_cmp()`].
+	///
+	/// See [`Self::simd_total_cmp_lt()`].
+	#[must_use]
+	#[inline]
+	fn simd_total_cmp_ge(self, other: Self) -> Self::Mask {
+		total_key(self).simd_ge(total_key(other))
+	}
+	/// Tests if each lane is equal to the corresponding lane in `other` in accordance to
+	/// [`Real::total_cmp()`].
+	///
+	/// See [`Self::simd_total_cmp_lt()`].
+	#[must_use]
+	#[inline]
+	fn simd_total_cmp_eq(self, other: Self) -> Self::Mask {
+		total_key(self).simd_eq(total_key(other))
+	}
+	/// Restricts each lane to the unit interval $[0, 1]$ unless it is NaN.
+	///
+	/// Equals `self.simd_clamp(Self::splat(R::ZERO), Self::splat(R::ONE))` like
+	/// [`Real::saturate()`], i.e., NaN lanes are preserved as for [`Self::simd_clamp()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// assert_eq!(Simd::<f32, 4>::splat(-0.5).saturate(), Simd::splat(0.0));
+	/// assert_eq!(Simd::<f32, 4>::splat(2.0).saturate(), Simd::splat(1.0));
+	/// let x = Simd::<f64, 4>::from_array([-3.0, 0.25, 7.0, f64::NAN]).saturate();
+	/// assert_eq!(x.to_array()[..3], [0.0, 0.25, 1.0]);
+	/// assert!(x[3].is_nan());
+	/// ```
+	#[must_use]
+	#[inline]
+	fn saturate(self) -> Self {
+		self.simd_clamp(Self::splat(R::ZERO), Self::splat(R::ONE))
+	}
+	/// Adds `other` to `self` and restricts each lane of the sum to the interval from `min` to
+	/// `max`.
+	///
+	/// Equals `(self + other).simd_clamp(Self::splat(min), Self::splat(max))`, e.g., for mixing
+	/// audio signals without clipping beyond $[-1, 1]$. NaN lanes remain NaN as for
+	/// [`Self::simd_clamp()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `min` is not less than or equal to `max`.
+	#[must_use]
+	#[inline]
+	fn add_clamped(self, other: Self, min: R, max: R) -> Self {
+		(self + other).simd_clamp(Self::splat(min), Self::splat(max))
+	}
+	/// Computes `self * a + b` as [`Self::mul_add()`] and restricts each lane to the interval from
+	/// `min` to `max`.
+	///
+	/// Equals `self.mul_add(a, b).simd_clamp(Self::splat(min), Self::splat(max))`, e.g., for mixing
+	/// audio signal `self` with gain `a` into `b`. NaN lanes remain NaN as for
+	/// [`Self::simd_clamp()`].
+	///
+	/// # Panics
+	///
+	/// Panics if `min` is not less than or equal to `max`.
+	#[must_use]
+	#[inline]
+	fn mul_add_clamped(self, a: Self, b: Self, min: R, max: R) -> Self {
+		self.mul_add(a, b)
+			.simd_clamp(Self::splat(min), Self::splat(max))
+	}
 
 	/// Takes the reciprocal (inverse) of each lane, ${1 \over x}$.
 	#[must_use]
 	fn recip(self) -> Self;
+	/// Divides each lane by the corresponding lane in `divisor` via a refined reciprocal.
+	///
+	/// Computes `self * divisor.recip()` where the reciprocal is estimated by bit manipulation
+	/// and refined by Newton-Raphson steps instead of dividing. Other than [`Div`], the result is
+	/// not correctly rounded but within $2$ ULP of it as long as it neither overflows nor
+	/// underflows.
+	///
+	/// Falls back to [`Div`] for the whole vector if any lane of `divisor` is zero, subnormal,
+	/// infinite, NaN, or too large in magnitude for its reciprocal to be normal. Hence, zero
+	/// divisors result in $\pm\infty$ and `0.0 / 0.0` results in NaN as with [`Div`].
+	#[must_use]
+	fn simd_div_refined(self, divisor: Self) -> Self;
 
 	/// Converts each lane from radians to degrees.
 	#[must_use]
@@ -392,25 +1584,615 @@ where
 	/// will be heavily dependant on designing algorithms with specific target hardware in mind.
 	#[must_use]
 	fn mul_add(self, a: Self, b: Self) -> Self;
+	/// Linearly interpolates between `self` and `b` by weight `t` of each lane like GLSL `mix`.
+	///
+	/// Forwards to [`Self::lerp()`] without clamping `t` to $[0, 1]$, i.e., it extrapolates for
+	/// `t` outside of $[0, 1]$.
+	#[must_use]
+	#[inline]
+	fn mix(self, b: Self, t: Self) -> Self {
+		self.lerp(b, t)
+	}
+	/// Linearly interpolates between `self` and `other` by weight `t` of each lane, see
+	/// [`Real::lerp()`].
+	///
+	/// Computes `self.mul_add(Self::splat(R::ONE) - t, other * t)`. The endpoints are exact, i.e.,
+	/// lanes of `t` equal to zero yield `self` and lanes equal to one yield `other`, whereas the
+	/// result is monotonic in `t` only up to the rounding error of `1 - t`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let a = Simd::<f32, 4>::from_array([0.1, -2.5, 1e30, 3.0]);
+	/// let b = Simd::from_array([7.3, 0.7, -1e-30, 5.0]);
+	/// let t = Simd::from_array([0.0, 1.0, 0.0, 0.5]);
+	/// assert_eq!(SimdReal::lerp(a, b, t).to_array(), [0.1, 0.7, 1e30, 4.0]);
+	/// let (a, b) = (Simd::<f64, 2>::splat(0.1), Simd::splat(7.3));
+	/// assert_eq!(SimdReal::lerp(a, b, Simd::splat(0.0)), a);
+	/// assert_eq!(SimdReal::lerp(a, b, Simd::splat(1.0)), b);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn lerp(self, other: Self, t: Self) -> Self {
+		self.mul_add(Self::splat(R::ONE) - t, other * t)
+	}
+	/// Selects lanes from `b` if their lane mask is true, otherwise from `self` like GLSL `mix`
+	/// with a boolean weight.
+	#[must_use]
+	#[inline]
+	fn mix_mask(self, b: Self, mask: Self::Mask) -> Self {
+		mask.select(b, self)
+	}
+	/// Chooses lanes from `if_true` if their lane `mask` is true, otherwise from `if_false`.
+	///
+	/// Forwards to [`Select::select()`] reading more naturally than `mask.select(..)` in numeric
+	/// code.
+	#[must_use]
+	#[inline]
+	fn blend(mask: Self::Mask, if_true: Self, if_false: Self) -> Self {
+		Select::select(mask, if_true, if_false)
+	}
+	/// Replaces NaN lanes with the lanes of `fallback` leaving all other lanes untouched.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdReal;
+	///
+	/// let x = Simd::<f32, 4>::from_array([1.0, f32::NAN, -0.0, f32::INFINITY]);
+	/// let y = x.replace_nan(Simd::splat(7.0));
+	/// assert_eq!(y.to_array(), [1.0, 7.0, -0.0, f32::INFINITY]);
+	/// assert!(y[2].is_sign_negative());
+	/// let x = Simd::<f64, 2>::from_array([-f64::NAN, 2.5]);
+	/// assert_eq!(
+	/// 	x.replace_nan(Simd::from_array([3.0, 4.0])).to_array(),
+	/// 	[3.0, 2.5]
+	/// );
+	/// ```
+	#[must_use]
+	#[inline]
+	fn replace_nan(self, fallback: Self) -> Self {
+		self.is_nan().select(fallback, self)
+	}
+	/// Evaluates the polynomial of `coeffs` at each lane using Horner's method.
+	///
+	/// Coefficients are ordered by ascending degree, i.e., computes
+	/// $\sum_{i} c_{i} x^{i}$ with one fused multiply-add per degree. Returns zero for empty
+	/// `coeffs`.
+	#[must_use]
+	#[inline]
+	fn simd_poly(self, coeffs: &[R]) -> Self {
+		coeffs.iter().rev().fold(Self::splat(R::ZERO), |acc, &c| {
+			acc.mul_add(self, Self::splat(c))
+		})
+	}
 	/// Produces a vector where every lane has the square root value of the equivalently-indexed
 	/// lane in `self`
 	#[must_use]
 	fn sqrt(self) -> Self;
+	/// Returns the reciprocal square root ${1 \over \sqrt{x}}$ of each lane as $x$, see
+	/// [`Real::recip_sqrt()`].
+	///
+	/// Equals `self.sqrt().recip()` by default which is within $1$ ULP of the correctly rounded
+	/// result, whereas implementations may use an approximation refined by Newton-Raphson steps
+	/// instead as long as it stays within $2$ ULP.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::{ApproxEq, SimdReal};
+	///
+	/// let x = Simd::<f32, 4>::from_array([4.0, 16.0, 0.25, 2.0]);
+	/// let y = Simd::from_array([0.5, 0.25, 2.0, core::f32::consts::FRAC_1_SQRT_2]);
+	/// assert!(x.recip_sqrt().approx_eq(&y, 0.0, 2));
+	/// let x = Simd::<f64, 4>::from_array([4.0, 16.0, 0.25, 2.0]);
+	/// let y = Simd::from_array([0.5, 0.25, 2.0, core::f64::consts::FRAC_1_SQRT_2]);
+	/// assert!(x.recip_sqrt().approx_eq(&y, 0.0, 2));
+	/// ```
+	#[must_use]
+	#[inline]
+	fn recip_sqrt(self) -> Self {
+		self.sqrt().recip()
+	}
+	/// Estimates the reciprocal square root ${1 \over \sqrt{x}}$ of each lane as $x$ by bit
+	/// manipulation without refinement, see [`Real::rsqrt_estimate()`].
+	///
+	/// The relative error is less than $3.5 \cdot 10^{-2}$. Lanes which are not positive normal
+	/// numbers fall back to [`Self::recip_sqrt()`].
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::{ApproxEqRel, SimdReal};
+	///
+	/// let x = Simd::<f32, 4>::from_array([4.0, 16.0, 0.25, 2.0]);
+	/// let y = Simd::from_array([0.5, 0.25, 2.0, core::f32::consts::FRAC_1_SQRT_2]);
+	/// assert!(x.recip_sqrt_approx().approx_eq_rel(&y, 3.5e-2));
+	/// let x = Simd::<f64, 4>::from_array([4.0, 16.0, 0.25, 0.0]);
+	/// let y = Simd::from_array([0.5, 0.25, 2.0, f64::INFINITY]);
+	/// assert!(x.recip_sqrt_approx().approx_eq_rel(&y, 3.5e-2));
+	/// ```
+	#[must_use]
+	fn recip_sqrt_approx(self) -> Self;
 	/// Returns the largest integer value less than or equal to each lane.
 	#[must_use]
 	fn floor(self) -> Self;
 	/// Returns the smallest integer greater than or equal to each lane.
 	#[must_use]
 	fn ceil(self) -> Self;
-	/// Rounds to the nearest integer value. Ties round toward zero.
+	/// Rounds to the nearest integer value. Ties round away from zero.
 	#[must_use]
 	fn round(self) -> Self;
+	/// Rounds to the nearest integer value. Ties round to the even integer value.
+	#[must_use]
+	#[inline]
+	fn round_ties_even(self) -> Self {
+		// Adding and subtracting $2^{p - 1}$ rounds to the nearest even integer by the current
+		// rounding mode whereas lanes of greater magnitude are integers already.
+		let magic = Self::splat(R::EPSILON.recip());
+		let abs = self.abs();
+		let rounded = ((abs + magic) - magic).copysign(self);
+		abs.simd_lt(magic).select(rounded, self)
+	}
 	/// Returns the floating point's integer value, with its fractional part removed.
 	#[must_use]
 	fn trunc(self) -> Self;
 	/// Returns the floating point's fractional value, with its integer part removed.
 	#[must_use]
 	fn fract(self) -> Self;
+	/// Calculates Euclidean division of each lane, the matching method for
+	/// [`Self::rem_euclid()`].
+	///
+	/// Truncates the quotient and adjusts it by one toward the sign of `rhs` where the truncated
+	/// remainder is negative like [`Real::div_euclid()`], i.e., NaN and zero-divisor lanes
+	/// yield the same results as the scalar version.
+	#[must_use]
+	#[inline]
+	fn div_euclid(self, rhs: Self) -> Self {
+		let q = (self / rhs).trunc();
+		let adjusted = rhs
+			.simd_gt(Self::splat(R::ZERO))
+			.select(q - Self::splat(R::ONE), q + Self::splat(R::ONE));
+		(self % rhs)
+			.simd_lt(Self::splat(R::ZERO))
+			.select(adjusted, q)
+	}
+	/// Calculates the least nonnegative remainder of each lane of `self (mod rhs)`.
+	///
+	/// Adds the magnitude of `rhs` where the truncated remainder is negative like
+	/// [`Real::rem_euclid()`], i.e., NaN and zero-divisor lanes yield the same results as the
+	/// scalar version.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::{Real, SimdReal};
+	///
+	/// let values = [
+	/// 	7.5,
+	/// 	-7.5,
+	/// 	3.0,
+	/// 	-3.0,
+	/// 	0.0,
+	/// 	-0.0,
+	/// 	1e-30,
+	/// 	f64::INFINITY,
+	/// 	f64::NAN,
+	/// ];
+	/// for x in values {
+	/// 	let rhs = Simd::from_array([2.0, -2.0, 3.0, -0.5, 0.0, f64::INFINITY, f64::NAN, 1e30]);
+	/// 	let (div, rem) = (
+	/// 		Simd::splat(x).div_euclid(rhs),
+	/// 		Simd::splat(x).rem_euclid(rhs),
+	/// 	);
+	/// 	for (lane, rhs) in rhs.to_array().into_iter().enumerate() {
+	/// 		let (scalar_div, scalar_rem) = (Real::div_euclid(x, rhs), Real::rem_euclid(x, rhs));
+	/// 		assert!(div[lane] == scalar_div || div[lane].is_nan() && scalar_div.is_nan());
+	/// 		assert!(rem[lane] == scalar_rem || rem[lane].is_nan() && scalar_rem.is_nan());
+	/// 	}
+	/// }
+	/// ```
+	#[must_use]
+	#[inline]
+	fn rem_euclid(self, rhs: Self) -> Self {
+		let r = self % rhs;
+		r.simd_lt(Self::splat(R::ZERO)).select(r + rhs.abs(), r)
+	}
+	/// Returns the integer value each lane is rounded to according to `mode`.
+	#[must_use]
+	#[inline]
+	fn round_mode(self, mode: RoundMode) -> Self {
+		match mode {
+			RoundMode::Nearest => self.round(),
+			RoundMode::TiesEven => self.round_ties_even(),
+			RoundMode::TowardZero => self.trunc(),
+			RoundMode::Down => self.floor(),
+			RoundMode::Up => self.ceil(),
+		}
+	}
+
+	/// Raises each lane to the integer power `n`.
+	///
+	/// Performs binary exponentiation on the whole vector and reciprocates for negative `n`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::{ApproxEq, SimdReal};
+	///
+	/// let x = Simd::<f32, 4>::splat(3.0);
+	/// assert!(SimdReal::powi(x, 5).approx_eq(&Simd::splat(243.0), 0.0, 2));
+	/// assert_eq!(SimdReal::powi(x, 0), Simd::splat(1.0));
+	/// let x = Simd::<f64, 4>::from_array([0.5, 2.0, -4.0, 10.0]);
+	/// let y = Simd::from_array([4.0, 0.25, 0.0625, 0.01]);
+	/// assert!(SimdReal::powi(x, -2).approx_eq(&y, 0.0, 2));
+	/// ```
+	#[must_use]
+	#[inline]
+	fn powi(self, n: i32) -> Self {
+		let (mut base, mut exp, mut pow) = (self, n.unsigned_abs(), Self::splat(R::ONE));
+		while exp != 0 {
+			if exp & 1 == 1 {
+				pow *= base;
+			}
+			base *= base;
+			exp >>= 1;
+		}
+		if n < 0 {
+			pow.recip()
+		} else {
+			pow
+		}
+	}
+	/// Raises the scalar `base` to the power of each lane.
+	///
+	/// Computes `(self * base.ln()).exp()` per lane where `base.ln()` is computed once. Unlike a
+	/// lanewise power of variable bases, the shared `base` hoists the logarithm out of the lanes.
+	/// The exponential maps each lane via [`Real::exp()`], see [`Self::exp_fast()`] for a
+	/// vectorized approximation.
+	///
+	/// Less accurate than [`Real::powf()`] as the rounding error of `self * base.ln()` is
+	/// amplified by the exponential, i.e., the result is off by up to about `|self * base.ln()|`
+	/// ULP in addition to the error of [`Real::exp()`]. Hence, large exponents lose many ULP.
+	///
+	/// A `base` of zero results in zero for positive lanes, infinity for negative lanes, and NaN
+	/// for zero lanes. A `base` of one results in one except for infinite and NaN lanes resulting
+	/// in NaN, whereas [`Real::powf()`] results in one. A negative `base` results in NaN for all
+	/// lanes, even for integer exponents.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::{ApproxEq, SimdReal};
+	///
+	/// let x = Simd::from_array([-2.0f64, 0.5, 3.0, 10.0]);
+	/// let y = x.exp_base(2.0).to_array();
+	/// for (y, x) in y.into_iter().zip(x.to_array()) {
+	/// 	assert!(y.approx_eq(&2f64.powf(x), 0.0, 8));
+	/// }
+	/// let x = Simd::from_array([0.0f64, 1.0, f64::INFINITY, f64::NAN]);
+	/// let y = x.exp_base(1.0).to_array();
+	/// assert_eq!(y[..2], [1.0, 1.0]);
+	/// assert!(y[2].is_nan() && y[3].is_nan());
+	/// assert_eq!(1f64.powf(f64::INFINITY), 1.0);
+	/// ```
+	#[must_use]
+	#[inline]
+	fn exp_base(self, base: R) -> Self {
+		Self::from_array((self * Self::splat(base.ln())).to_array().map(Real::exp))
+	}
+	/// Computes the sine of each lane in radians.
+	///
+	/// Reduces each lane to $[-\frac{\pi}{4}, \frac{\pi}{4}]$ and evaluates minimax polynomials on
+	/// the whole vector. The error is within 2 ULP over $[-\pi, \pi]$ and grows with the magnitude
+	/// due to the reduction. Maps each lane via [`Real::sin()`] in case of `libm`.
+	#[must_use]
+	fn sin(self) -> Self;
+	/// Computes the cosine of each lane in radians.
+	///
+	/// Reduces each lane to $[-\frac{\pi}{4}, \frac{\pi}{4}]$ and evaluates minimax polynomials on
+	/// the whole vector. The error is within 2 ULP over $[-\pi, \pi]$ and grows with the magnitude
+	/// due to the reduction. Maps each lane via [`Real::cos()`] in case of `libm`.
+	#[must_use]
+	fn cos(self) -> Self;
+	/// Simultaneously computes the sine and cosine of each lane in radians.
+	///
+	/// Returns `(self.sin(), self.cos())` at the cost of a single reduction as both polynomials
+	/// are evaluated anyway. Maps each lane via [`Real::sin_cos()`] in case of `libm`.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use lav::{ApproxEq, Bits, Real, SimdReal};
+	///
+	/// fn check<R: Real>() {
+	/// 	let ulp = R::Bits::ONE + R::Bits::ONE;
+	/// 	for i in -2_500..2_500i16 {
+	/// 		let x = R::Simd::<4>::from_array(
+	/// 			[0, 1, 2, 3u8].map(|j| R::from(i) * R::from(4u8) + R::from(j)),
+	/// 		) * R::Simd::splat(R::PI / R::from(10_000u16));
+	/// 		let (sin, cos) = x.sin_cos();
+	/// 		assert_eq!((x.sin(), x.cos()), (sin, cos));
+	/// 		for (lane, x) in x.to_array().into_iter().enumerate() {
+	/// 			assert!(sin[lane].approx_eq(&x.sin(), R::ZERO, ulp));
+	/// 			assert!(cos[lane].approx_eq(&x.cos(), R::ZERO, ulp));
+	/// 		}
+	/// 	}
+	/// }
+	///
+	/// check::<f32>();
+	/// check::<f64>();
+	/// ```
+	#[must_use]
+	fn sin_cos(self) -> (Self, Self);
+	/// Computes the points on the unit circle at the angle of each lane in radians, see
+	/// [`Real::cis()`].
+	///
+	/// Returns the vectors of `(real, imag)` parts, i.e., `(cos, sin)`, mapping each lane via
+	/// [`Real::sin_cos()`].
+	#[must_use]
+	#[inline]
+	fn simd_cis(self) -> (Self, Self) {
+		let (mut cos, mut sin) = ([R::ZERO; N], [R::ZERO; N]);
+		for (lane, angle) in self.to_array().into_iter().enumerate() {
+			(sin[lane], cos[lane]) = angle.sin_cos();
+		}
+		(Self::from_array(cos), Self::from_array(sin))
+	}
+	/// Computes the error function of each lane, see [`Real::erf()`].
+	///
+	/// Maps each lane via [`Real::erf()`].
+	#[must_use]
+	#[inline]
+	fn simd_erf(self) -> Self {
+		Self::from_array(self.to_array().map(Real::erf))
+	}
+	/// Computes the complementary error function of each lane, see [`Real::erfc()`].
+	///
+	/// Maps each lane via [`Real::erfc()`] and hence is as accurate in the tail.
+	#[must_use]
+	#[inline]
+	fn simd_erfc(self) -> Self {
+		Self::from_array(self.to_array().map(Real::erfc))
+	}
+	/// Computes the logistic sigmoid function of each lane, see [`Real::sigmoid()`].
+	///
+	/// Maps each lane via [`Real::sigmoid()`].
+	#[must_use]
+	#[inline]
+	fn simd_sigmoid(self) -> Self {
+		Self::from_array(self.to_array().map(Real::sigmoid))
+	}
+	/// Computes the softplus function of each lane, see [`Real::softplus()`].
+	///
+	/// Maps each lane via [`Real::softplus()`].
+	#[must_use]
+	#[inline]
+	fn simd_softplus(self) -> Self {
+		Self::from_array(self.to_array().map(Real::softplus))
+	}
+	/// Applies the Reinhard tone mapping curve $\frac{x}{1 + x}$ to each lane.
+	///
+	/// Maps high dynamic range lanes in $[0, \infty]$ to $[0, 1]$. Negative lanes are clamped to
+	/// zero first, as are NaN lanes.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::{ApproxEq, SimdReal};
+	///
+	/// let x = Simd::from_array([-1.0f32, 0.0, 1.0, 0.18]);
+	/// let y = Simd::from_array([0.0, 0.0, 0.5, 0.18 / 1.18]);
+	/// assert!(x.tonemap_reinhard().approx_eq(&y, 0.0, 1));
+	/// ```
+	#[must_use]
+	#[inline]
+	fn tonemap_reinhard(self) -> Self {
+		let x = self.simd_max(Self::splat(R::ZERO));
+		x * (x + Self::splat(R::ONE)).recip()
+	}
+	/// Applies the ACES filmic tone mapping curve to each lane.
+	///
+	/// Evaluates Narkowicz's rational approximation
+	///
+	/// $$
+	/// \frac{x (2.51 x + 0.03)}{x (2.43 x + 0.59) + 0.14}
+	/// $$
+	///
+	/// and clamps the result to $[0, 1]$. Negative lanes are clamped to zero first, as are NaN
+	/// lanes.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::{ApproxEq, SimdReal};
+	///
+	/// // Mid-gray maps to about `0.267`, whereas highlights saturate.
+	/// let x = Simd::from_array([-1.0f64, 0.0, 0.18, 100.0]);
+	/// let y = Simd::from_array([0.0, 0.0, 0.266_898_920_389_496_8, 1.0]);
+	/// assert!(x.tonemap_aces().approx_eq(&y, 0.0, 4));
+	/// let x = Simd::from_array([0.18f32, f32::NAN]);
+	/// let y = Simd::from_array([0.266_898_92, 0.0]);
+	/// assert!(x.tonemap_aces().approx_eq(&y, 0.0, 4));
+	/// ```
+	#[must_use]
+	#[inline]
+	fn tonemap_aces(self) -> Self {
+		let hundred = R::from(100u8);
+		let a = Self::splat(R::from(251u16) / hundred);
+		let b = Self::splat(R::from(3u8) / hundred);
+		let c = Self::splat(R::from(243u8) / hundred);
+		let d = Self::splat(R::from(59u8) / hundred);
+		let e = Self::splat(R::from(14u8) / hundred);
+		let zero = Self::splat(R::ZERO);
+		let x = self.simd_max(zero);
+		let numerator = x * x.mul_add(a, b);
+		let denominator = x.mul_add(x.mul_add(c, d), e);
+		(numerator * denominator.recip()).simd_clamp(zero, Self::splat(R::ONE))
+	}
+
+	/// Approximates the sine of each lane.
+	///
+	/// Reduces each lane to $[-\frac{\pi}{2}, \frac{\pi}{2}]$ and evaluates an odd polynomial of
+	/// degree 9. The absolute error is less than `1e-5` for lanes of up to `100` in magnitude and
+	/// grows with the magnitude due to the reduction. Prefer the precise sine unless latency is
+	/// critical.
+	#[must_use]
+	#[inline]
+	fn sin_fast(self) -> Self {
+		let k = (self * Self::splat(R::FRAC_1_PI)).round();
+		sin_reduced(k.mul_add(Self::splat(-R::PI), self), k)
+	}
+	/// Approximates the cosine of each lane.
+	///
+	/// Reduces each lane to $[-\frac{\pi}{2}, \frac{\pi}{2}]$ and evaluates an odd polynomial of
+	/// degree 9. The absolute error is less than `1e-5` for lanes of up to `100` in magnitude and
+	/// grows with the magnitude due to the reduction. Prefer the precise cosine unless latency is
+	/// critical.
+	#[must_use]
+	#[inline]
+	fn cos_fast(self) -> Self {
+		let k = (self * Self::splat(R::FRAC_1_PI) - Self::splat(R::FRAC_1_2)).round()
+			+ Self::splat(R::FRAC_1_2);
+		-sin_reduced(
+			k.mul_add(Self::splat(-R::PI), self),
+			k - Self::splat(R::FRAC_1_2),
+		)
+	}
+	/// Approximates the four quadrant arctangent of `self` as $y$ and `other` as $x$ in radians.
+	///
+	/// Evaluates an odd polynomial of degree 11 of the ratio of the lesser to the greater
+	/// magnitude and maps it to the quadrant. The absolute error is less than `2e-6`. Returns NaN
+	/// if any lane is NaN or both lanes are infinite. Prefer the precise arctangent unless latency
+	/// is critical.
+	#[must_use]
+	fn atan2_fast(self, other: Self) -> Self;
+	/// Approximates the exponential function of each lane.
+	///
+	/// Splits each lane into an integral and a fractional power of two and evaluates a polynomial
+	/// of degree 5 for the latter. The relative error is less than `1e-5` unless the result is
+	/// subnormal. Prefer the precise exponential function unless latency is critical.
+	#[must_use]
+	#[inline]
+	fn exp_fast(self) -> Self {
+		#[allow(clippy::cast_possible_truncation)]
+		let bias = Self::splat(R::from((R::MAX_EXP - 1) as i16));
+		let t = (self * Self::splat(R::LOG2_E)).simd_clamp(-bias - bias, bias + bias);
+		let k = t.round();
+		let p = (t - k).simd_poly(&[
+			R::ONE,
+			R::LN_2,
+			R::LN_2.powi(2) / R::from(2u8),
+			R::LN_2.powi(3) / R::from(6u8),
+			R::LN_2.powi(4) / R::from(24u8),
+			R::LN_2.powi(5) / R::from(120u8),
+		]);
+		// Splits the power of two into two normal halves as `2^k` itself might overflow.
+		let pow2 = |e: Self| Self::from_bits((e + bias).simd_to_fixed(R::MANTISSA_DIGITS - 1));
+		let h = (k * Self::splat(R::FRAC_1_2)).floor();
+		let exp = p * pow2(h) * pow2(k - h);
+		self.is_nan().select(self, exp)
+	}
+	/// Approximates the natural logarithm of each lane.
+	///
+	/// Splits each lane into an integral power of two and a mantissa in
+	/// $[\frac{1}{\sqrt{2}}, \sqrt{2}]$ and evaluates an odd polynomial of degree 7 for the
+	/// latter. The relative error is less than `5e-6` except near one where the absolute error is
+	/// less than `5e-7`. Subnormal lanes are treated as zero. Prefer the precise natural logarithm
+	/// unless latency is critical.
+	#[must_use]
+	#[inline]
+	fn ln_fast(self) -> Self {
+		#[allow(clippy::cast_possible_truncation)]
+		let bias = Self::splat(R::from((R::MAX_EXP - 1) as i16));
+		let one = Self::splat(R::ONE);
+		let bits = self.to_bits();
+		let mantissa = Self::Bits::splat(R::MIN_POSITIVE.to_bits() - R::Bits::ONE);
+		let m = Self::from_bits(bits & mantissa | Self::Bits::splat(R::ONE.to_bits()));
+		let exponent = bits & Self::Bits::splat(R::INFINITY.to_bits());
+		let e = Self::simd_from_fixed(exponent, R::MANTISSA_DIGITS - 1) - bias;
+		let above = m.simd_gt(Self::splat(R::SQRT_2));
+		let m = above.select(m * Self::splat(R::FRAC_1_2), m);
+		let e = above.select(e + one, e);
+		let u = (m - one) / (m + one);
+		let p = (u * u).simd_poly(&[
+			R::ONE,
+			R::FRAC_1_3,
+			R::from(5u8).recip(),
+			R::from(7u8).recip(),
+		]);
+		let ln = e.mul_add(
+			Self::splat(R::LN_2_HI),
+			e.mul_add(Self::splat(R::LN_2_LO), (u + u) * p),
+		);
+		let ln = self
+			.simd_lt(Self::splat(R::MIN_POSITIVE))
+			.select(Self::splat(R::NEG_INFINITY), ln);
+		let ln = self.simd_eq(Self::splat(R::INFINITY)).select(self, ln);
+		(self.simd_lt(Self::splat(R::ZERO)) | self.is_nan()).select(Self::splat(R::NAN), ln)
+	}
+
+	/// Finds a root of `f` with derivative `df` per lane by Newton-Raphson steps starting at `x0`.
+	///
+	/// Steps each lane like [`Real::newton_step()`] and freezes it once its step is not greater
+	/// than `tol` in magnitude while the other lanes continue. Stops when all lanes are frozen or
+	/// after at most `max_iter` steps, so all lanes are stepped `max_iter` times in the worst case
+	/// as `f` and `df` are evaluated for the whole vector. Returns the last iterate of each lane.
+	#[must_use]
+	#[inline]
+	fn simd_newton_solve(
+		f: impl Fn(Self) -> Self,
+		df: impl Fn(Self) -> Self,
+		x0: Self,
+		tol: R,
+		max_iter: usize,
+	) -> Self {
+		let mut x = x0;
+		let mut active = Self::Mask::splat(true);
+		for _ in 0..max_iter {
+			let (f, df) = (f(x), df(x));
+			let flat = df.abs().simd_le(Self::splat(R::EPSILON) * f.abs());
+			let next = flat.select(x, x - f / df);
+			let step = (next - x).abs();
+			x = active.select(next, x);
+			active &= step.simd_gt(Self::splat(tol));
+			if !active.any() {
+				break;
+			}
+		}
+		x
+	}
 
 	/// Converts an array to a SIMD vector mask.
 	#[must_use]
@@ -424,4 +2206,164 @@ where
 	fn mask_flag(lane: usize, value: bool) -> Self::Mask {
 		Self::Mask::flag(lane, value)
 	}
+	/// Converts each lane to its truth value, i.e., whether it is nonzero.
+	///
+	/// Returns `false` for each lane if its value is `0.0`, `-0.0`, or NaN. Unlike the
+	/// truthiness of shading languages, NaN is considered `false` as it is not a number.
+	///
+	/// See [`Self::mask_to_real`] for the reverse conversion.
+	#[must_use]
+	#[inline]
+	fn simd_to_mask(self) -> Self::Mask {
+		self.simd_ne(Self::splat(R::ZERO)) & !self.is_nan()
+	}
+	/// Converts each lane mask to `1.0` if it is `true` and to `0.0` if it is `false`.
+	///
+	/// Converting the result back via [`Self::simd_to_mask`] results in the same mask.
+	#[must_use]
+	#[inline]
+	fn mask_to_real(mask: Self::Mask) -> Self {
+		mask.select(Self::splat(R::ONE), Self::splat(R::ZERO))
+	}
+}
+
+/// Approximates the sine of the reduced lanes `r` negating lanes of odd multiples `k` of $\pi$.
+fn sin_reduced<R: Real, S: SimdReal<R, N>, const N: usize>(r: S, k: S) -> S
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	let sin = r
+		* (r * r).simd_poly(&[
+			R::ONE,
+			-R::FRAC_1_6,
+			R::from(120u8).recip(),
+			-R::from(5_040u16).recip(),
+			(R::from(5_040u16) * R::from(72u8)).recip(),
+		]);
+	let odd = (k * S::splat(R::FRAC_1_2))
+		.fract()
+		.simd_ne(S::splat(R::ZERO));
+	odd.negate(sin)
+}
+
+/// Approximates the four quadrant arctangent of `y` and `x` in radians.
+///
+/// Evaluates the odd polynomial of coefficients `atan` of the ratio of the lesser to the greater
+/// magnitude and maps it to the quadrant.
+fn atan2_reduced<R: Real, S: SimdReal<R, N>, const N: usize>(y: S, x: S, atan: &[R]) -> S
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	let (abs_y, abs_x) = (y.abs(), x.abs());
+	let (min, max) = (abs_y.simd_min(abs_x), abs_y.simd_max(abs_x));
+	let zero = S::splat(R::ZERO);
+	let a = max.simd_eq(zero).select(zero, min / max);
+	let r = a * (a * a).simd_poly(atan);
+	let r = abs_y.simd_gt(abs_x).select(S::splat(R::FRAC_PI_2) - r, r);
+	let r = x.is_sign_negative().select(S::splat(R::PI) - r, r);
+	let r = r.copysign(y);
+	(y.is_nan() | x.is_nan()).select(S::splat(R::NAN), r)
+}
+
+/// Computes the sine and cosine of `x`.
+///
+/// Reduces `x` by multiples of $\frac{\pi}{2}$ split into the three parts of `pio2` and evaluates
+/// the sine and cosine polynomials of even coefficients `sin` and `cos` beyond the quadratic term.
+#[cfg(not(feature = "libm"))]
+fn sin_cos_reduced<R: Real, S: SimdReal<R, N>, const N: usize>(
+	x: S,
+	pio2: [R; 3],
+	sin: &[R],
+	cos: &[R],
+) -> (S, S)
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	let q = (x * S::splat(R::FRAC_2_PI)).round();
+	let r = pio2
+		.into_iter()
+		.fold(x, |r, pio2| q.mul_add(S::splat(-pio2), r));
+	let s = r * r;
+	let sin = (r * s).mul_add(s.simd_poly(sin), r);
+	let cos = S::splat(R::ONE) - (S::splat(R::FRAC_1_2) * s - s * s * s.simd_poly(cos));
+	let odd = (q * S::splat(R::FRAC_1_2))
+		.fract()
+		.simd_ne(S::splat(R::ZERO));
+	let negative = |q: S| {
+		let quarter = q * S::splat(R::FRAC_1_2 * R::FRAC_1_2);
+		(quarter - quarter.floor()).simd_ge(S::splat(R::FRAC_1_2))
+	};
+	(
+		negative(q).negate(odd.select(cos, sin)),
+		negative(q + S::splat(R::ONE)).negate(odd.select(sin, cos)),
+	)
+}
+
+/// Maximum number of lanes [`SimdReal::compact()`] looks up its permutation for.
+const COMPACT_LANES: usize = 8;
+
+/// Permutations of lane indices packing the set bits of the bitmask indexing them into the low
+/// lanes, where the indices of the remaining lanes are out of bounds.
+#[allow(clippy::cast_possible_truncation)]
+const COMPACT_IDXS: [[u8; COMPACT_LANES]; 1 << COMPACT_LANES] = {
+	let mut table = [[COMPACT_LANES as u8; COMPACT_LANES]; 1 << COMPACT_LANES];
+	let mut bitmask = 0;
+	while bitmask < table.len() {
+		let (mut lane, mut count) = (0, 0);
+		while lane < COMPACT_LANES {
+			if bitmask >> lane & 1 == 1 {
+				table[bitmask][count] = lane as u8;
+				count += 1;
+			}
+			lane += 1;
+		}
+		bitmask += 1;
+	}
+	table
+};
+
+/// Computes the unsigned integer keys of `real` ordered in accordance to [`Real::total_cmp()`].
+///
+/// Flips all bits of negative lanes and the sign bit of all other lanes.
+fn total_key<R: Real, S: SimdReal<R, N>, const N: usize>(real: S) -> S::Bits
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	let sign = S::splat(-R::ZERO).to_bits();
+	real.to_bits() ^ real.is_sign_negative().select(!S::Bits::default(), sign)
+}
+
+/// Computes the indices `start + lane * stride` asserting they are less than `len`.
+fn strided_idxs<const N: usize>(len: usize, start: usize, stride: usize) -> Simd<usize, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	let end = (N - 1)
+		.checked_mul(stride)
+		.and_then(|end| end.checked_add(start));
+	assert!(
+		end.is_some_and(|end| end < len),
+		"strided index out of bounds"
+	);
+	Simd::from_array(array::from_fn(|lane| start + lane * stride))
+}
+
+/// Swizzle swapping lanes separated by stride `S`.
+struct SwapStride<const S: usize>;
+
+impl<const N: usize, const S: usize> Swizzle<N> for SwapStride<S> {
+	const INDEX: [usize; N] = {
+		assert!(N.is_power_of_two(), "lane count must be a power of two");
+		assert!(
+			S.is_power_of_two() && S < N,
+			"stride must be a power of two less than lane count"
+		);
+		let mut index = [0; N];
+		let mut lane = 0;
+		while lane < N {
+			index[lane] = lane ^ S;
+			lane += 1;
+		}
+		index
+	};
 }
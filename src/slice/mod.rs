@@ -0,0 +1,176 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{Real, SimdReal};
+use core::{
+	iter::FusedIterator,
+	simd::{LaneCount, SupportedLaneCount},
+	slice::ChunksExact,
+};
+
+/// Extension trait of slices of [`Real`] numbers splitting them into SIMD vectors.
+pub trait SimdSlice<R: Real> {
+	/// Splits the slice into an iterator over SIMD vectors of `N` lanes and a scalar tail.
+	///
+	/// Unlike [`Real::as_simd()`], there is no prefix. The vectors are loaded from consecutive
+	/// chunks of `N` lanes starting at the first lane, whereas the tail consists of the remaining
+	/// `len % N` lanes. Hence, a misaligned slice which would result in a non-empty prefix is
+	/// handled by loading unaligned vectors instead of processing the prefix lane by lane.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::SimdSlice;
+	///
+	/// let xs = (0..11u8).map(f32::from).collect::<Vec<f32>>();
+	/// // Length is a multiple of `N`.
+	/// let (chunks, tail) = xs[..8].simd_chunks::<4>();
+	/// assert_eq!(chunks.len(), 2);
+	/// let sum = chunks.fold(Simd::splat(0.0), |sum, x| sum + x);
+	/// assert_eq!(sum.to_array(), [4.0, 6.0, 8.0, 10.0]);
+	/// assert!(tail.is_empty());
+	/// // Length is not a multiple of `N` and the slice is possibly misaligned.
+	/// let (chunks, tail) = xs[1..].simd_chunks::<4>();
+	/// let chunks = chunks.map(|x| x.to_array()).collect::<Vec<_>>();
+	/// assert_eq!(chunks, [[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]);
+	/// assert_eq!(tail, [9.0, 10.0]);
+	/// // Slice is shorter than `N`.
+	/// let (mut chunks, tail) = xs[..3].simd_chunks::<4>();
+	/// assert_eq!((chunks.next(), tail), (None, &xs[..3]));
+	/// ```
+	#[must_use]
+	fn simd_chunks<const N: usize>(&self) -> (SimdChunks<'_, R, N>, &[R])
+	where
+		LaneCount<N>: SupportedLaneCount;
+}
+
+impl<R: Real> SimdSlice<R> for [R] {
+	#[inline]
+	fn simd_chunks<const N: usize>(&self) -> (SimdChunks<'_, R, N>, &[R])
+	where
+		LaneCount<N>: SupportedLaneCount,
+	{
+		let chunks = self.chunks_exact(N);
+		let tail = chunks.remainder();
+		(SimdChunks { chunks }, tail)
+	}
+}
+
+/// Computes `acc[i] += a[i] * b[i]` with one rounding via SIMD vectors of `N` lanes.
+///
+/// Processes consecutive chunks of `N` lanes via [`SimdReal::mul_add()`] and the remaining
+/// `len % N` lanes via [`Real::mul_add()`], see [`SimdSlice::simd_chunks()`]. Hence, misaligned
+/// slices are handled by loading and storing unaligned vectors instead of falling back to scalar
+/// operations for a prefix.
+///
+/// # Panics
+///
+/// Panics if the slices are of different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use lav::{fma_slice, ApproxEq, Bits, Real};
+///
+/// fn check<R: Real>() {
+/// 	for len in [1, 4, 9, 14] {
+/// 		let a = (0..len)
+/// 			.map(|i| R::from(i) / R::from(3u8) - R::ONE)
+/// 			.collect::<Vec<R>>();
+/// 		let b = (0..len)
+/// 			.map(|i| R::from(25u8 - i) / R::from(7u8))
+/// 			.collect::<Vec<R>>();
+/// 		let acc = (0..len).map(R::from).collect::<Vec<R>>();
+/// 		// Possibly misaligned slices of lengths `len` and `len - 1`.
+/// 		for start in [0, 1] {
+/// 			let (a, b, mut acc) = (&a[start..], &b[start..], acc[start..].to_vec());
+/// 			let mut naive = acc.clone();
+/// 			for ((a, b), acc) in a.iter().zip(b).zip(&mut naive) {
+/// 				*acc = a.mul_add(*b, *acc);
+/// 			}
+/// 			fma_slice::<R, 4>(a, b, &mut acc);
+/// 			// Within one ULP as the vectorized `mul_add` is unfused in case of `libm`.
+/// 			for (acc, naive) in acc.iter().zip(&naive) {
+/// 				assert!(acc.approx_eq(naive, R::ZERO, R::Bits::ONE));
+/// 			}
+/// 		}
+/// 	}
+/// }
+///
+/// check::<f32>();
+/// check::<f64>();
+/// ```
+#[inline]
+pub fn fma_slice<R: Real, const N: usize>(a: &[R], b: &[R], acc: &mut [R])
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	assert!(
+		a.len() == acc.len() && b.len() == acc.len(),
+		"slices of different lengths"
+	);
+	let ((a, a_tail), (b, b_tail)) = (a.simd_chunks::<N>(), b.simd_chunks::<N>());
+	let mut acc = acc.chunks_exact_mut(N);
+	for ((a, b), acc) in a.zip(b).zip(acc.by_ref()) {
+		let sum = a.mul_add(b, R::Simd::from_slice(acc));
+		acc.copy_from_slice(sum.as_array());
+	}
+	for ((a, b), acc) in a_tail.iter().zip(b_tail).zip(acc.into_remainder()) {
+		*acc = a.mul_add(*b, *acc);
+	}
+}
+
+/// Iterator over SIMD vectors of `N` lanes, see [`SimdSlice::simd_chunks()`].
+#[derive(Debug, Clone)]
+pub struct SimdChunks<'a, R: Real, const N: usize>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	chunks: ChunksExact<'a, R>,
+}
+
+impl<R: Real, const N: usize> Iterator for SimdChunks<'_, R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	type Item = R::Simd<N>;
+
+	#[inline]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.chunks.next().map(R::Simd::from_slice)
+	}
+	#[inline]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.chunks.size_hint()
+	}
+	#[inline]
+	fn nth(&mut self, n: usize) -> Option<Self::Item> {
+		self.chunks.nth(n).map(R::Simd::from_slice)
+	}
+}
+
+impl<R: Real, const N: usize> DoubleEndedIterator for SimdChunks<'_, R, N>
+where
+	LaneCount<N>: SupportedLaneCount,
+{
+	#[inline]
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.chunks.next_back().map(R::Simd::from_slice)
+	}
+}
+
+impl<R: Real, const N: usize> ExactSizeIterator for SimdChunks<'_, R, N> where
+	LaneCount<N>: SupportedLaneCount
+{
+}
+
+impl<R: Real, const N: usize> FusedIterator for SimdChunks<'_, R, N> where
+	LaneCount<N>: SupportedLaneCount
+{
+}
@@ -0,0 +1,185 @@
+// Copyright © 2021-2024 Rouven Spreckels <rs@qu1x.dev>
+//
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use super::{Real, SimdReal};
+use core::simd::{LaneCount, SupportedLaneCount};
+
+/// Running statistics of streaming samples by Welford's online algorithm.
+///
+/// Tracks the count, mean, and sum of squared deviations from the mean of all samples pushed so
+/// far in a numerically stable way. Samples are pushed one by one via [`Self::push()`] or a whole
+/// SIMD vector at once via [`Self::push_simd()`] which combines the statistics of its lanes with
+/// the running ones.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+///
+/// use lav::{ApproxEqRel, Real, SimdReal, Welford};
+///
+/// fn check<R: Real>() {
+/// 	let xs = (0..128u16)
+/// 		.map(|i| R::from(i * 37 % 101) / R::from(7u8) + R::from(1_000u16))
+/// 		.collect::<Vec<R>>();
+/// 	// Two-pass reference.
+/// 	let n = R::from(128u8);
+/// 	let mean = xs.iter().sum::<R>() / n;
+/// 	let variance = xs.iter().map(|&x| (x - mean) * (x - mean)).sum::<R>() / n;
+/// 	let rel = R::EPSILON * R::from(64u8);
+/// 	let check = |stats: &Welford<R>| {
+/// 		assert_eq!(stats.count(), 128);
+/// 		assert!(stats.mean().approx_eq_rel(&mean, rel));
+/// 		assert!(stats.variance().approx_eq_rel(&variance, rel));
+/// 	};
+/// 	let mut pushed = Welford::new();
+/// 	xs.iter().for_each(|&x| pushed.push(x));
+/// 	check(&pushed);
+/// 	let mut pushed_simd = Welford::new();
+/// 	for chunk in xs.chunks_exact(8) {
+/// 		pushed_simd.push_simd(R::Simd::<8>::from_slice(chunk));
+/// 	}
+/// 	check(&pushed_simd);
+/// 	let (mut merged, mut other) = (Welford::new(), Welford::new());
+/// 	xs[..29].iter().for_each(|&x| merged.push(x));
+/// 	xs[29..].iter().for_each(|&x| other.push(x));
+/// 	merged.merge(&other);
+/// 	merged.merge(&Welford::new());
+/// 	check(&merged);
+/// }
+///
+/// check::<f32>();
+/// check::<f64>();
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Welford<R: Real> {
+	count: u64,
+	mean: R,
+	m2: R,
+}
+
+impl<R: Real> Welford<R> {
+	/// Creates running statistics without samples.
+	#[must_use]
+	#[inline]
+	pub const fn new() -> Self {
+		Self {
+			count: 0,
+			mean: R::ZERO,
+			m2: R::ZERO,
+		}
+	}
+	/// Pushes sample `x`.
+	#[inline]
+	pub fn push(&mut self, x: R) {
+		self.count += 1;
+		let delta = x - self.mean;
+		self.mean += delta / real::<R>(self.count);
+		self.m2 = delta.mul_add(x - self.mean, self.m2);
+	}
+	/// Pushes the lanes of `v` as `N` samples.
+	///
+	/// Computes the mean and the sum of squared deviations of the lanes first and combines them
+	/// with the running statistics via [`Self::merge()`].
+	#[inline]
+	pub fn push_simd<const N: usize>(&mut self, v: R::Simd<N>)
+	where
+		LaneCount<N>: SupportedLaneCount,
+	{
+		let count = N as u64;
+		let mean = v.reduce_sum() / real::<R>(count);
+		let deviation = v - R::Simd::splat(mean);
+		let m2 = (deviation * deviation).reduce_sum();
+		self.merge(&Self { count, mean, m2 });
+	}
+	/// Merges the running statistics of `other` into `self`.
+	///
+	/// Combines both as if all samples of `other` were pushed to `self` using the parallel
+	/// algorithm of Chan et al.
+	#[inline]
+	pub fn merge(&mut self, other: &Self) {
+		if other.count == 0 {
+			return;
+		}
+		let count = self.count + other.count;
+		let delta = other.mean - self.mean;
+		let weight = real::<R>(other.count) / real::<R>(count);
+		self.mean = delta.mul_add(weight, self.mean);
+		self.m2 = (delta * delta * real::<R>(self.count)).mul_add(weight, self.m2 + other.m2);
+		self.count = count;
+	}
+	/// Returns the number of samples.
+	///
+	/// # Examples
+	///
+	/// ```
+	/// #![feature(portable_simd)]
+	///
+	/// use core::simd::Simd;
+	/// use lav::Welford;
+	///
+	/// let mut stats = Welford::<f32>::new();
+	/// for _ in 0..(1 << 18) + 1 {
+	/// 	stats.push_simd(Simd::<f32, 64>::splat(0.5));
+	/// }
+	/// assert_eq!(stats.count(), (1 << 24) + 64);
+	/// assert_eq!(stats.mean(), 0.5);
+	/// ```
+	#[must_use]
+	#[inline]
+	pub const fn count(&self) -> u64 {
+		self.count
+	}
+	/// Returns the mean of the samples or zero without samples.
+	#[must_use]
+	#[inline]
+	pub const fn mean(&self) -> R {
+		self.mean
+	}
+	/// Returns the population variance of the samples or NaN without samples.
+	#[must_use]
+	#[inline]
+	pub fn variance(&self) -> R {
+		self.m2 / real::<R>(self.count)
+	}
+	/// Returns the sample variance of the samples with Bessel's correction or NaN with less than
+	/// two samples.
+	#[must_use]
+	#[inline]
+	pub fn sample_variance(&self) -> R {
+		if self.count > 1 {
+			self.m2 / real::<R>(self.count - 1)
+		} else {
+			R::NAN
+		}
+	}
+	/// Returns the population standard deviation of the samples or NaN without samples.
+	#[must_use]
+	#[inline]
+	pub fn std_dev(&self) -> R {
+		self.variance().sqrt()
+	}
+}
+
+impl<R: Real> Default for Welford<R> {
+	#[inline]
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+/// Converts `count` to the nearest real number.
+///
+/// Composes it of 16-bit digits as [`Real`] converts losslessly from [`u16`] only.
+fn real<R: Real>(count: u64) -> R {
+	let radix = R::from(256u16) * R::from(256u16);
+	count
+		.to_be_bytes()
+		.chunks_exact(2)
+		.fold(R::ZERO, |real, digit| {
+			real * radix + R::from(u16::from_be_bytes([digit[0], digit[1]]))
+		})
+}